@@ -291,6 +291,45 @@ impl TestFileBuilder {
         Ok(file_path)
     }
 
+    /// Generate a file with specific ED2K chunks corrupted
+    ///
+    /// Content is otherwise deterministic (byte value equals its offset modulo 256), so
+    /// chunks *not* listed in `chunk_indices` hash consistently across calls and only the
+    /// listed chunks have their bytes flipped. This lets tests inject corruption at known
+    /// chunk boundaries and assert that [`crate::verification::FileVerifier`] flags exactly
+    /// those chunks.
+    pub fn generate_corrupted_file_with_chunks(
+        &mut self,
+        name: &str,
+        size: usize,
+        chunk_indices: &[usize],
+    ) -> Result<PathBuf> {
+        let file_path = self.base_dir.join(name);
+
+        let mut content: Vec<u8> = (0..size).map(|i| (i % 256) as u8).collect();
+
+        for &chunk_index in chunk_indices {
+            let start = chunk_index * crate::verification::ED2K_CHUNK_SIZE;
+            if start >= size {
+                continue;
+            }
+            let end = (start + crate::verification::ED2K_CHUNK_SIZE).min(size);
+            for byte in &mut content[start..end] {
+                *byte = !*byte;
+            }
+        }
+
+        std::fs::write(&file_path, content).map_err(|e| {
+            Error::Internal(InternalError::ffi(
+                "test_file_builder",
+                &format!("Failed to write corrupted test file: {e}"),
+            ))
+        })?;
+
+        self.generated_files.push(file_path.clone());
+        Ok(file_path)
+    }
+
     /// Clean up all generated files
     pub fn cleanup(&mut self) {
         for file_path in &self.generated_files {
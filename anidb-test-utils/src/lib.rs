@@ -6,8 +6,10 @@
 pub mod builders;
 pub mod mocks;
 pub mod performance;
+pub mod verification;
 
 // Re-export commonly used types
 pub use builders::{TestDataBuilder, TestFileBuilder};
 pub use mocks::{MockAniDBClient, MockFileSystem};
 pub use performance::{CoverageReporter, PerformanceTracker, TestHarness};
+pub use verification::{ChunkMismatch, FileVerifier};
@@ -2,14 +2,29 @@
 //!
 //! Temporary placeholders for components not yet migrated from core.
 
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::time::{Duration, Instant, SystemTime};
 
+/// Env var CI can set to pin a deterministic [`TestHarness::run_all_tests_shuffled`] order
+const TEST_SEED_ENV_VAR: &str = "ANIDB_TEST_SEED";
+
+/// Number of bootstrap resamples used to estimate a confidence interval for the mean
+const BOOTSTRAP_RESAMPLES: usize = 10_000;
+
+/// Width of the bootstrap confidence interval (95%)
+const BOOTSTRAP_CI_PERCENTILE_LOW: f64 = 0.025;
+const BOOTSTRAP_CI_PERCENTILE_HIGH: f64 = 0.975;
+
 /// Performance tracking and baseline establishment
 pub struct PerformanceTracker {
     metrics: HashMap<String, Vec<PerformanceMetric>>,
     baselines: HashMap<String, PerformanceBaseline>,
-    active_operations: HashMap<u64, (String, Instant, u64)>, // id -> (name, start_time, start_memory)
+    // id -> (name, start_time, start_memory, bytes_processed)
+    active_operations: HashMap<u64, (String, Instant, u64, Option<u64>)>,
     operation_counter: u64,
 }
 
@@ -19,6 +34,16 @@ pub struct PerformanceMetric {
     pub memory_usage: Option<u64>,
     pub peak_memory: Option<u64>,
     pub timestamp: SystemTime,
+    /// Bytes processed by the operation, when tracked via `start_tracking_with_throughput`
+    pub bytes_processed: Option<u64>,
+}
+
+impl PerformanceMetric {
+    /// Throughput in bytes/second, if this metric was tracked with a byte count
+    pub fn bytes_per_second(&self) -> Option<f64> {
+        self.bytes_processed
+            .map(|bytes| bytes as f64 / self.duration.as_secs_f64())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -28,6 +53,16 @@ pub struct PerformanceBaseline {
     pub max_duration: Duration,
     pub average_memory: Option<u64>,
     pub sample_count: usize,
+    /// Raw durations the baseline was computed from, kept so the bootstrap can be redone
+    pub raw_samples: Vec<Duration>,
+    /// Bootstrap point estimate of the mean duration (equal to `average_duration`)
+    pub point_estimate: Duration,
+    /// Lower bound of the 95% bootstrap confidence interval for the mean
+    pub ci_lower: Duration,
+    /// Upper bound of the 95% bootstrap confidence interval for the mean
+    pub ci_upper: Duration,
+    /// Standard deviation of the bootstrap resample means
+    pub std_dev: Duration,
 }
 
 #[derive(Debug)]
@@ -35,6 +70,37 @@ pub struct RegressionInfo {
     pub regression_factor: f64,
     pub baseline_duration: Duration,
     pub current_duration: Duration,
+    /// True when the current duration falls outside the baseline's bootstrap CI,
+    /// as opposed to merely exceeding `threshold` on noisy data
+    pub statistically_significant: bool,
+}
+
+/// Draw `resamples` bootstrap samples (with replacement) of the given durations and
+/// return the mean of each resample, sorted ascending.
+fn bootstrap_resample_means(durations: &[Duration], resamples: usize, seed: u64) -> Vec<f64> {
+    let nanos: Vec<f64> = durations.iter().map(|d| d.as_nanos() as f64).collect();
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut means: Vec<f64> = (0..resamples)
+        .map(|_| {
+            let sum: f64 = (0..nanos.len())
+                .map(|_| nanos[rng.random_range(0..nanos.len())])
+                .sum();
+            sum / nanos.len() as f64
+        })
+        .collect();
+
+    means.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    means
+}
+
+/// Compute the value at a given percentile (0.0..=1.0) of an already-sorted slice
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let idx = ((sorted.len() - 1) as f64 * p).round() as usize;
+    sorted[idx.min(sorted.len() - 1)]
 }
 
 impl PerformanceTracker {
@@ -55,6 +121,19 @@ impl PerformanceTracker {
 
     /// Start tracking an operation
     pub fn start_tracking(&mut self, operation_name: &str) -> u64 {
+        self.start_tracking_inner(operation_name, None)
+    }
+
+    /// Start tracking an operation that processes a known number of bytes
+    ///
+    /// Use this for throughput-sensitive operations (e.g. hashing) so the resulting
+    /// metric can report `bytes_per_second`, letting runs over differently-sized
+    /// inputs be compared as MiB/s rather than raw wall-clock.
+    pub fn start_tracking_with_throughput(&mut self, operation_name: &str, bytes: u64) -> u64 {
+        self.start_tracking_inner(operation_name, Some(bytes))
+    }
+
+    fn start_tracking_inner(&mut self, operation_name: &str, bytes: Option<u64>) -> u64 {
         self.operation_counter += 1;
         let operation_id = self.operation_counter;
 
@@ -62,7 +141,7 @@ impl PerformanceTracker {
 
         self.active_operations.insert(
             operation_id,
-            (operation_name.to_string(), Instant::now(), start_memory),
+            (operation_name.to_string(), Instant::now(), start_memory, bytes),
         );
 
         operation_id
@@ -70,7 +149,7 @@ impl PerformanceTracker {
 
     /// Finish tracking an operation
     pub fn finish_tracking(&mut self, operation_id: u64) {
-        if let Some((operation_name, start_time, start_memory)) =
+        if let Some((operation_name, start_time, start_memory, bytes_processed)) =
             self.active_operations.remove(&operation_id)
         {
             let duration = start_time.elapsed();
@@ -81,6 +160,7 @@ impl PerformanceTracker {
                 memory_usage: Some(end_memory.saturating_sub(start_memory)),
                 peak_memory: Some(end_memory),
                 timestamp: SystemTime::now(),
+                bytes_processed,
             };
 
             self.metrics.entry(operation_name).or_default().push(metric);
@@ -94,6 +174,24 @@ impl PerformanceTracker {
             .and_then(|metrics| metrics.last())
     }
 
+    /// Record a metric for a duration measured elsewhere (e.g. by [`TestHarness::watch`],
+    /// which already timed the run via `CaseResult::duration`), instead of via
+    /// `start_tracking`/`finish_tracking`'s own `Instant` pair
+    pub fn record_metric(&mut self, operation_name: &str, duration: Duration) {
+        let metric = PerformanceMetric {
+            duration,
+            memory_usage: None,
+            peak_memory: None,
+            timestamp: SystemTime::now(),
+            bytes_processed: None,
+        };
+
+        self.metrics
+            .entry(operation_name.to_string())
+            .or_default()
+            .push(metric);
+    }
+
     /// Establish baseline for an operation
     pub fn establish_baseline(&mut self, operation_name: &str) {
         if let Some(metrics) = self.metrics.get(operation_name) {
@@ -117,12 +215,33 @@ impl PerformanceTracker {
                 Some(memory_values.iter().sum::<u64>() / memory_values.len() as u64)
             };
 
+            // Bootstrap a 95% confidence interval for the mean so regression checks
+            // aren't thrown off by a single noisy sample on a loaded CI machine.
+            let resample_means = bootstrap_resample_means(&durations, BOOTSTRAP_RESAMPLES, 42);
+            let ci_lower =
+                Duration::from_nanos(percentile(&resample_means, BOOTSTRAP_CI_PERCENTILE_LOW) as u64);
+            let ci_upper =
+                Duration::from_nanos(percentile(&resample_means, BOOTSTRAP_CI_PERCENTILE_HIGH) as u64);
+
+            let resample_mean_avg = resample_means.iter().sum::<f64>() / resample_means.len() as f64;
+            let variance = resample_means
+                .iter()
+                .map(|m| (m - resample_mean_avg).powi(2))
+                .sum::<f64>()
+                / resample_means.len() as f64;
+            let std_dev = Duration::from_nanos(variance.sqrt() as u64);
+
             let baseline = PerformanceBaseline {
                 average_duration,
                 min_duration,
                 max_duration,
                 average_memory,
                 sample_count: metrics.len(),
+                raw_samples: durations,
+                point_estimate: average_duration,
+                ci_lower,
+                ci_upper,
+                std_dev,
             };
 
             self.baselines.insert(operation_name.to_string(), baseline);
@@ -135,23 +254,64 @@ impl PerformanceTracker {
     }
 
     /// Check for performance regression
+    ///
+    /// A regression is flagged when the latest measurement exceeds `ci_upper * threshold`,
+    /// i.e. is slower than `threshold`x the upper bound of the baseline's bootstrap
+    /// confidence interval rather than its raw average. `RegressionInfo::statistically_significant`
+    /// further distinguishes a measurement that falls outside the CI entirely from one
+    /// that's merely above threshold within expected noise.
     pub fn check_regression(&self, operation_name: &str, threshold: f64) -> Option<RegressionInfo> {
         let baseline = self.baselines.get(operation_name)?;
         let latest_metric = self.get_metrics(operation_name)?;
 
-        let regression_factor =
-            latest_metric.duration.as_nanos() as f64 / baseline.average_duration.as_nanos() as f64;
+        let current_nanos = latest_metric.duration.as_nanos() as f64;
+        let ci_upper_nanos = baseline.ci_upper.as_nanos() as f64;
+        let regression_factor = current_nanos / baseline.average_duration.as_nanos() as f64;
+
+        if current_nanos > ci_upper_nanos * threshold {
+            let statistically_significant =
+                current_nanos > ci_upper_nanos || current_nanos < baseline.ci_lower.as_nanos() as f64;
 
-        if regression_factor > threshold {
             Some(RegressionInfo {
                 regression_factor,
                 baseline_duration: baseline.average_duration,
                 current_duration: latest_metric.duration,
+                statistically_significant,
             })
         } else {
             None
         }
     }
+
+    /// Generate a report of tracked operations, including a throughput column (MiB/s)
+    /// for operations started with `start_tracking_with_throughput`
+    pub fn generate_report(&self) -> String {
+        let mut report = String::new();
+
+        report.push_str("Performance Report\n");
+        report.push_str("===================\n\n");
+
+        for (operation_name, metrics) in &self.metrics {
+            let Some(latest) = metrics.last() else {
+                continue;
+            };
+
+            match latest.bytes_per_second() {
+                Some(bps) => {
+                    let mib_per_sec = bps / (1024.0 * 1024.0);
+                    report.push_str(&format!(
+                        "{operation_name}: {:?} ({mib_per_sec:.2} MiB/s)\n",
+                        latest.duration
+                    ));
+                }
+                None => {
+                    report.push_str(&format!("{operation_name}: {:?}\n", latest.duration));
+                }
+            }
+        }
+
+        report
+    }
 }
 
 impl Default for PerformanceTracker {
@@ -236,6 +396,41 @@ impl CoverageReporter {
 
         report
     }
+
+    /// Render coverage as JSON, e.g. for CI dashboards
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "modules": self.module_coverage,
+            "categories": self.category_coverage,
+            "thresholds": self.coverage_thresholds,
+            "overall": self.get_overall_coverage(),
+        })
+    }
+
+    /// Render coverage as a JUnit XML report, one `<testcase>` per module with its
+    /// coverage percentage recorded as system-out
+    pub fn to_junit_xml(&self) -> String {
+        let mut xml = String::new();
+        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str(&format!(
+            "<testsuite name=\"coverage\" tests=\"{}\">\n",
+            self.module_coverage.len()
+        ));
+
+        for (module, coverage) in &self.module_coverage {
+            xml.push_str(&format!(
+                "  <testcase name=\"{}\" time=\"0\">\n",
+                escape_xml(module)
+            ));
+            xml.push_str(&format!(
+                "    <system-out>{coverage:.1}%</system-out>\n"
+            ));
+            xml.push_str("  </testcase>\n");
+        }
+
+        xml.push_str("</testsuite>\n");
+        xml
+    }
 }
 
 impl Default for CoverageReporter {
@@ -247,6 +442,10 @@ impl Default for CoverageReporter {
 /// Test execution framework
 pub struct TestHarness {
     test_cases: HashMap<String, Box<dyn Fn() -> anidb_client_core::Result<()> + Send + Sync>>,
+    // Registration order, kept separately since `test_cases` is a HashMap
+    case_order: Vec<String>,
+    // Files each case depends on, used by `watch` to determine what to re-run
+    case_paths: HashMap<String, Vec<PathBuf>>,
     benchmarks: HashMap<String, Box<dyn Fn() -> anidb_client_core::Result<()> + Send + Sync>>,
     mock_file_system: Option<crate::mocks::MockFileSystem>,
     test_generator: Option<crate::builders::TestFileBuilder>,
@@ -259,6 +458,78 @@ pub struct TestResults {
     pub passed_tests: usize,
     pub failed_tests: usize,
     pub duration: Duration,
+    /// Seed used to shuffle test order, when run via `run_all_tests_shuffled`
+    pub seed: Option<u64>,
+    /// Per-case outcome, in the order each case actually ran
+    pub cases: Vec<CaseResult>,
+}
+
+/// Outcome of a single test case within a `TestResults`
+#[derive(Debug, Clone)]
+pub struct CaseResult {
+    pub name: String,
+    pub passed: bool,
+    pub duration: Duration,
+    pub error: Option<String>,
+}
+
+impl TestResults {
+    /// Render these results as JSON, e.g. for CI dashboards
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "total_tests": self.total_tests,
+            "passed_tests": self.passed_tests,
+            "failed_tests": self.failed_tests,
+            "duration_secs": self.duration.as_secs_f64(),
+            "seed": self.seed,
+            "cases": self.cases.iter().map(|c| serde_json::json!({
+                "name": c.name,
+                "passed": c.passed,
+                "duration_secs": c.duration.as_secs_f64(),
+                "error": c.error,
+            })).collect::<Vec<_>>(),
+        })
+    }
+
+    /// Render these results as a JUnit XML report, consumable by most CI dashboards
+    pub fn to_junit_xml(&self, suite_name: &str) -> String {
+        let mut xml = String::new();
+        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str(&format!(
+            "<testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" time=\"{:.3}\">\n",
+            escape_xml(suite_name),
+            self.total_tests,
+            self.failed_tests,
+            self.duration.as_secs_f64()
+        ));
+
+        for case in &self.cases {
+            xml.push_str(&format!(
+                "  <testcase name=\"{}\" time=\"{:.3}\">\n",
+                escape_xml(&case.name),
+                case.duration.as_secs_f64()
+            ));
+            if let Some(error) = &case.error {
+                xml.push_str(&format!(
+                    "    <failure message=\"{}\"/>\n",
+                    escape_xml(error)
+                ));
+            }
+            xml.push_str("  </testcase>\n");
+        }
+
+        xml.push_str("</testsuite>\n");
+        xml
+    }
+}
+
+/// Escape characters JUnit XML attribute/text values can't contain verbatim
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
 }
 
 impl TestHarness {
@@ -266,6 +537,8 @@ impl TestHarness {
     pub fn new() -> Self {
         Self {
             test_cases: HashMap::new(),
+            case_order: Vec::new(),
+            case_paths: HashMap::new(),
             benchmarks: HashMap::new(),
             mock_file_system: None,
             test_generator: None,
@@ -283,25 +556,278 @@ impl TestHarness {
     where
         F: Fn() -> anidb_client_core::Result<()> + Send + Sync + 'static,
     {
+        self.case_order.push(name.to_string());
         self.test_cases.insert(name.to_string(), Box::new(test_fn));
     }
 
-    /// Run all test cases
+    /// Add a test case together with the file paths it depends on
+    ///
+    /// `watch` uses these paths to determine which cases are affected when a file
+    /// changes, so only the relevant subset gets re-run instead of the whole suite.
+    pub fn add_watched_test_case<F>(&mut self, name: &str, paths: Vec<PathBuf>, test_fn: F)
+    where
+        F: Fn() -> anidb_client_core::Result<()> + Send + Sync + 'static,
+    {
+        self.add_test_case(name, test_fn);
+        self.case_paths.insert(name.to_string(), paths);
+    }
+
+    /// Run all test cases in insertion order
     pub fn run_all_tests(&self) -> TestResults {
+        self.run_ordered(self.case_order.clone(), None)
+    }
+
+    /// Run all test cases in an order permuted by `seed`
+    ///
+    /// Insertion order hides bugs where one test case leaves global/mock state that
+    /// affects another. Shuffling with a reported seed surfaces that inter-test coupling
+    /// while keeping failures reproducible: the seed is recorded on the returned
+    /// `TestResults` and printed on failure, so a flaky ordering can be replayed exactly
+    /// by passing the same seed back in.
+    pub fn run_all_tests_shuffled(&self, seed: u64) -> TestResults {
+        let mut order = self.case_order.clone();
+        let mut rng = StdRng::seed_from_u64(seed);
+        order.shuffle(&mut rng);
+
+        self.run_ordered(order, Some(seed))
+    }
+
+    /// Resolve the seed CI should use for `run_all_tests_shuffled`
+    ///
+    /// Honors the `ANIDB_TEST_SEED` env var so a flaky ordering reported in CI can be
+    /// pinned and reproduced locally; otherwise derives a seed from the current time.
+    pub fn resolve_shuffle_seed() -> u64 {
+        std::env::var(TEST_SEED_ENV_VAR)
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or_else(|| {
+                SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_nanos() as u64
+            })
+    }
+
+    /// Determine which registered cases are affected by the given changed paths
+    ///
+    /// Cases registered without declared dependencies (via `add_test_case`) are
+    /// considered always-affected, since we have no basis to exclude them.
+    fn affected_cases(&self, changed_paths: &[PathBuf]) -> Vec<String> {
+        self.case_order
+            .iter()
+            .filter(|name| match self.case_paths.get(*name) {
+                Some(paths) => paths.iter().any(|p| changed_paths.contains(p)),
+                None => true,
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Recursively collect every file under `path` into `out` (or just `path` itself, if
+    /// it's already a file)
+    fn collect_files(path: &std::path::Path, out: &mut Vec<PathBuf>) {
+        if path.is_dir() {
+            if let Ok(entries) = std::fs::read_dir(path) {
+                for entry in entries.flatten() {
+                    Self::collect_files(&entry.path(), out);
+                }
+            }
+        } else {
+            out.push(path.to_path_buf());
+        }
+    }
+
+    /// Snapshot the current modification time of every file under `paths` (each a file or,
+    /// scanned recursively, a directory)
+    fn snapshot_mtimes(paths: &[PathBuf]) -> HashMap<PathBuf, SystemTime> {
+        let mut files = Vec::new();
+        for path in paths {
+            Self::collect_files(path, &mut files);
+        }
+
+        files
+            .into_iter()
+            .filter_map(|path| {
+                let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+                Some((path, modified))
+            })
+            .collect()
+    }
+
+    /// Re-scan `paths` and return every file whose modification time advanced past what's
+    /// recorded in `last_modified` (which is updated in place, including for files seen for
+    /// the first time)
+    fn detect_changes(
+        paths: &[PathBuf],
+        last_modified: &mut HashMap<PathBuf, SystemTime>,
+    ) -> Vec<PathBuf> {
+        let mut files = Vec::new();
+        for path in paths {
+            Self::collect_files(path, &mut files);
+        }
+
+        let mut changed = Vec::new();
+        for path in files {
+            let Ok(modified) = std::fs::metadata(&path).and_then(|m| m.modified()) else {
+                continue;
+            };
+
+            let is_changed = match last_modified.get(&path) {
+                Some(previous) => modified > *previous,
+                None => true,
+            };
+            if is_changed {
+                changed.push(path.clone());
+            }
+            last_modified.insert(path, modified);
+        }
+
+        changed
+    }
+
+    /// Record `duration` as `name`'s latest sample, warning if it regresses against the
+    /// baseline accumulated from prior watch iterations, then fold it into a refreshed
+    /// baseline so the next iteration's comparison accounts for it too
+    fn track_and_report_regression(tracker: &mut PerformanceTracker, name: &str, duration: Duration) {
+        let had_baseline = tracker.get_baseline(name).is_some();
+        tracker.record_metric(name, duration);
+
+        if had_baseline && let Some(regression) = tracker.check_regression(name, 1.5) {
+            println!(
+                "⚠ {name} regressed {:.2}x (baseline {:?}, now {:?})",
+                regression.regression_factor, regression.baseline_duration, regression.current_duration
+            );
+        }
+
+        tracker.establish_baseline(name);
+    }
+
+    /// Watch `paths` (each a file or, scanned recursively, a directory — e.g. the temp dirs
+    /// [`crate::builders::TestFileBuilder`] writes into) for changes, and re-run the
+    /// affected cases and benchmarks whenever something changes, without exiting
+    ///
+    /// Cases registered via [`Self::add_watched_test_case`] only re-run when one of their
+    /// declared dependency paths is among the changed files; cases registered via
+    /// [`Self::add_test_case`] (with no declared paths) are always considered affected,
+    /// since there's no basis to exclude them. Every benchmark re-runs on a change, subject
+    /// only to `name_pattern`.
+    ///
+    /// Polls modification times every `poll_interval`; once a change is seen, waits
+    /// `debounce` and re-scans before reacting, so a burst of saves from an editor
+    /// collapses into a single re-run instead of one per file touched. After each poll,
+    /// `should_continue` is invoked to decide whether to keep watching; returning `false`
+    /// stops the loop. This keeps the loop testable and lets a CLI wire it up to e.g. a
+    /// Ctrl-C flag.
+    ///
+    /// `name_pattern`, when given, restricts each re-run to cases and benchmarks whose name
+    /// contains it, so e.g. `watch(paths, interval, debounce, Some("ed2k"), ...)` only
+    /// re-runs ED2K-related work on a change instead of the whole affected set.
+    ///
+    /// Every run's case and benchmark durations are folded into this harness's
+    /// `PerformanceTracker` (creating one on first use), so baselines — and therefore
+    /// `check_regression` deltas — accumulate across watch iterations instead of resetting
+    /// on every run.
+    pub fn watch(
+        &mut self,
+        paths: &[PathBuf],
+        poll_interval: Duration,
+        debounce: Duration,
+        name_pattern: Option<&str>,
+        mut should_continue: impl FnMut() -> bool,
+    ) {
+        if self.performance_tracker.is_none() {
+            self.performance_tracker = Some(PerformanceTracker::new());
+        }
+
+        let mut last_modified = Self::snapshot_mtimes(paths);
+
+        loop {
+            std::thread::sleep(poll_interval);
+
+            let mut changed_paths = Self::detect_changes(paths, &mut last_modified);
+
+            if !changed_paths.is_empty() {
+                // Debounce: give a burst of rapid saves a chance to settle before reacting,
+                // so it collapses into one re-run instead of one per file touched.
+                std::thread::sleep(debounce);
+                changed_paths.extend(Self::detect_changes(paths, &mut last_modified));
+
+                let affected: Vec<String> = self
+                    .affected_cases(&changed_paths)
+                    .into_iter()
+                    .filter(|name| name_pattern.is_none_or(|pattern| name.contains(pattern)))
+                    .collect();
+                let affected_benchmarks: Vec<String> = self
+                    .benchmarks
+                    .keys()
+                    .filter(|name| name_pattern.is_none_or(|pattern| name.contains(pattern)))
+                    .cloned()
+                    .collect();
+
+                println!(
+                    "Watch: {} file(s) changed, re-running {} case(s) and {} benchmark(s)",
+                    changed_paths.len(),
+                    affected.len(),
+                    affected_benchmarks.len()
+                );
+
+                let results = self.run_ordered(affected, None);
+                let benchmark_durations = self.run_benchmarks_named(&affected_benchmarks);
+
+                let tracker = self
+                    .performance_tracker
+                    .as_mut()
+                    .expect("initialized at the top of watch");
+                for case in &results.cases {
+                    Self::track_and_report_regression(tracker, &case.name, case.duration);
+                }
+                for (name, duration) in &benchmark_durations {
+                    Self::track_and_report_regression(tracker, name, *duration);
+                }
+            }
+
+            if !should_continue() {
+                break;
+            }
+        }
+    }
+
+    fn run_ordered(&self, order: Vec<String>, seed: Option<u64>) -> TestResults {
         let start_time = Instant::now();
-        let total_tests = self.test_cases.len();
+        let total_tests = order.len();
         let mut passed_tests = 0;
         let mut failed_tests = 0;
+        let mut cases = Vec::with_capacity(total_tests);
+
+        for name in &order {
+            let Some(test_fn) = self.test_cases.get(name) else {
+                continue;
+            };
 
-        for (name, test_fn) in &self.test_cases {
+            let case_start = Instant::now();
             match test_fn() {
                 Ok(()) => {
                     passed_tests += 1;
                     println!("✓ {name}");
+                    cases.push(CaseResult {
+                        name: name.clone(),
+                        passed: true,
+                        duration: case_start.elapsed(),
+                        error: None,
+                    });
                 }
                 Err(e) => {
                     failed_tests += 1;
-                    println!("✗ {name}: {e}");
+                    match seed {
+                        Some(seed) => println!("✗ {name}: {e} (seed={seed})"),
+                        None => println!("✗ {name}: {e}"),
+                    }
+                    cases.push(CaseResult {
+                        name: name.clone(),
+                        passed: false,
+                        duration: case_start.elapsed(),
+                        error: Some(e.to_string()),
+                    });
                 }
             }
         }
@@ -311,6 +837,8 @@ impl TestHarness {
             passed_tests,
             failed_tests,
             duration: start_time.elapsed(),
+            seed,
+            cases,
         }
     }
 
@@ -325,9 +853,18 @@ impl TestHarness {
 
     /// Run benchmarks
     pub fn run_benchmarks(&self) -> HashMap<String, Duration> {
+        self.run_benchmarks_named(&self.benchmarks.keys().cloned().collect::<Vec<_>>())
+    }
+
+    /// Run only the named benchmarks, e.g. the subset `watch` decides is affected by a change
+    fn run_benchmarks_named(&self, names: &[String]) -> HashMap<String, Duration> {
         let mut results = HashMap::new();
 
-        for (name, benchmark_fn) in &self.benchmarks {
+        for name in names {
+            let Some(benchmark_fn) = self.benchmarks.get(name) else {
+                continue;
+            };
+
             let start_time = Instant::now();
             match benchmark_fn() {
                 Ok(()) => {
@@ -370,6 +907,12 @@ impl TestHarness {
     pub fn has_performance_tracker(&self) -> bool {
         self.performance_tracker.is_some()
     }
+
+    /// Access this harness's performance tracker, e.g. to inspect baselines `watch` has
+    /// accumulated across its iterations
+    pub fn performance_tracker(&self) -> Option<&PerformanceTracker> {
+        self.performance_tracker.as_ref()
+    }
 }
 
 impl Default for TestHarness {
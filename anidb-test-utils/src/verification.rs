@@ -0,0 +1,70 @@
+//! File verification utilities for pinpointing ED2K chunk-level corruption
+//!
+//! Complements `TestFileBuilder::generate_corrupted_file_with_chunks`: given a file and
+//! the chunk hashes it was expected to produce, reports exactly which ED2K chunks don't
+//! match instead of a single pass/fail signal.
+
+use anidb_client_core::error::{Error, InternalError, Result};
+use md4::{Digest, Md4};
+use std::ops::Range;
+use std::path::Path;
+
+/// ED2K chunk size in bytes (9500 KiB), matching the production ED2K hasher's chunk size
+pub const ED2K_CHUNK_SIZE: usize = 9_728_000;
+
+/// A single ED2K chunk whose recomputed hash didn't match what was expected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkMismatch {
+    pub index: usize,
+    pub expected: String,
+    pub actual: String,
+    pub byte_range: Range<u64>,
+}
+
+/// Verifies a file against a vector of expected ED2K chunk hashes
+pub struct FileVerifier;
+
+impl FileVerifier {
+    /// Recompute the MD4 hash of each ED2K chunk in `path` and compare it against
+    /// `expected_chunk_hashes`, returning one [`ChunkMismatch`] per chunk that differs.
+    ///
+    /// `expected_chunk_hashes` should have one entry per `ED2K_CHUNK_SIZE`-byte chunk
+    /// (the final chunk may be shorter than a full chunk). Chunks beyond the end of the
+    /// file are ignored rather than reported as mismatches.
+    pub fn verify_chunks(
+        path: &Path,
+        expected_chunk_hashes: &[String],
+    ) -> Result<Vec<ChunkMismatch>> {
+        let data = std::fs::read(path).map_err(|e| {
+            Error::Internal(InternalError::ffi(
+                "file_verifier",
+                &format!("Failed to read file for verification: {e}"),
+            ))
+        })?;
+
+        let mut mismatches = Vec::new();
+
+        for (index, expected) in expected_chunk_hashes.iter().enumerate() {
+            let start = index * ED2K_CHUNK_SIZE;
+            if start >= data.len() {
+                break;
+            }
+            let end = (start + ED2K_CHUNK_SIZE).min(data.len());
+
+            let mut hasher = Md4::new();
+            hasher.update(&data[start..end]);
+            let actual = format!("{:x}", hasher.finalize());
+
+            if &actual != expected {
+                mismatches.push(ChunkMismatch {
+                    index,
+                    expected: expected.clone(),
+                    actual,
+                    byte_range: start as u64..end as u64,
+                });
+            }
+        }
+
+        Ok(mismatches)
+    }
+}
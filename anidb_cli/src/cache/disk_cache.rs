@@ -0,0 +1,399 @@
+//! Disk-backed cache implementation with a size budget
+//!
+//! This module provides a persistent cache that, unlike [`FileCache`](super::file_cache::FileCache),
+//! bounds itself by total on-disk footprint rather than only entry count or TTL. When the
+//! configured `disk_usage` budget is exceeded, least-recently-used entries are evicted until the
+//! cache is back under budget, following the storage-config pattern used by content-addressed
+//! stores such as IPFS. Because `HashResult`s are cheap to recompute from the source file,
+//! evicting under pressure is safe and gives callers a crash-resilient cache that won't grow
+//! without bound.
+
+use crate::cache::traits::HashCache;
+use crate::cache::{CacheEntry, CacheKey, CacheStats};
+use anidb_client_core::error::{Error, InternalError, Result};
+use anidb_client_core::hashing::HashResult;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::RwLock;
+
+/// Default disk usage budget: ~1 GiB, in KiB.
+const DEFAULT_DISK_USAGE_KB: u64 = 1024 * 1024;
+
+/// Configuration for the disk-backed cache
+#[derive(Debug, Clone)]
+pub struct DiskCacheConfig {
+    /// Maximum on-disk footprint, in KiB, before LRU eviction kicks in
+    pub disk_usage_kb: u64,
+    /// Default TTL for entries
+    pub default_ttl: Duration,
+}
+
+impl Default for DiskCacheConfig {
+    fn default() -> Self {
+        Self {
+            disk_usage_kb: DEFAULT_DISK_USAGE_KB,
+            default_ttl: Duration::from_secs(86400 * 30), // 30 days
+        }
+    }
+}
+
+/// Disk-backed cache with a capacity budget and LRU eviction
+///
+/// Entries are persisted to a single `cache.json` file under `cache_dir`, mirroring
+/// [`FileCache`](super::file_cache::FileCache). In addition to TTL-based expiry, the total
+/// estimated size of all entries is tracked against `disk_usage_kb`; once the budget is
+/// exceeded, the least-recently-used entries are evicted (oldest `last_accessed` first) until
+/// the cache fits.
+pub struct DiskCache {
+    cache_dir: PathBuf,
+    entries: Arc<RwLock<HashMap<CacheKey, CacheEntry>>>,
+    stats: Arc<RwLock<CacheStats>>,
+    config: DiskCacheConfig,
+}
+
+impl DiskCache {
+    /// Create a new disk cache with the default ~1 GiB budget
+    #[allow(dead_code)]
+    pub fn new(cache_dir: PathBuf) -> Result<Self> {
+        Self::with_config(cache_dir, DiskCacheConfig::default())
+    }
+
+    /// Create a new disk cache with a custom budget and TTL
+    pub fn with_config(cache_dir: PathBuf, config: DiskCacheConfig) -> Result<Self> {
+        if !cache_dir.exists() {
+            std::fs::create_dir_all(&cache_dir).map_err(|e| {
+                Error::Internal(InternalError::assertion(format!(
+                    "Failed to create cache directory: {e}"
+                )))
+            })?;
+        }
+
+        let entries = Self::load_from_disk(&cache_dir).unwrap_or_default();
+        let entry_count = entries.len();
+        let total_size_bytes = entries.values().map(Self::estimate_entry_size).sum();
+
+        Ok(Self {
+            cache_dir,
+            entries: Arc::new(RwLock::new(entries)),
+            stats: Arc::new(RwLock::new(CacheStats {
+                entry_count,
+                hit_count: 0,
+                miss_count: 0,
+                total_size_bytes,
+                eviction_count: 0,
+            })),
+            config,
+        })
+    }
+
+    /// Configured disk usage budget, in bytes
+    fn budget_bytes(&self) -> u64 {
+        self.config.disk_usage_kb.saturating_mul(1024)
+    }
+
+    /// Estimate the on-disk footprint of a single entry
+    fn estimate_entry_size(entry: &CacheEntry) -> u64 {
+        std::mem::size_of::<CacheEntry>() as u64 + entry.hash_result.hash.len() as u64
+    }
+
+    /// Evict least-recently-used entries until total size is within budget
+    fn evict_until_within_budget(
+        entries: &mut HashMap<CacheKey, CacheEntry>,
+        stats: &mut CacheStats,
+        budget_bytes: u64,
+    ) {
+        while stats.total_size_bytes > budget_bytes && !entries.is_empty() {
+            let Some(lru_key) = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_accessed)
+                .map(|(k, _)| k.clone())
+            else {
+                break;
+            };
+
+            if let Some(removed) = entries.remove(&lru_key) {
+                stats.entry_count -= 1;
+                stats.eviction_count += 1;
+                stats.total_size_bytes = stats
+                    .total_size_bytes
+                    .saturating_sub(Self::estimate_entry_size(&removed));
+            }
+        }
+    }
+
+    /// Load cache entries from disk
+    fn load_from_disk(cache_dir: &std::path::Path) -> Result<HashMap<CacheKey, CacheEntry>> {
+        let cache_file = cache_dir.join("cache.json");
+
+        if !cache_file.exists() {
+            return Ok(HashMap::new());
+        }
+
+        let data = std::fs::read_to_string(&cache_file).map_err(|e| {
+            Error::Internal(InternalError::assertion(format!(
+                "Failed to read cache: {e}"
+            )))
+        })?;
+
+        let entries_vec: Vec<(CacheKey, CacheEntry)> =
+            serde_json::from_str(&data).map_err(|e| {
+                Error::Internal(InternalError::assertion(format!(
+                    "Failed to parse cache: {e}"
+                )))
+            })?;
+
+        let now = SystemTime::now();
+        let valid_entries: HashMap<CacheKey, CacheEntry> = entries_vec
+            .into_iter()
+            .filter(|(_, entry)| entry.expires_at.is_none_or(|expires_at| expires_at > now))
+            .collect();
+
+        Ok(valid_entries)
+    }
+
+    /// Save cache entries to disk
+    async fn save_to_disk(&self, entries: &HashMap<CacheKey, CacheEntry>) -> Result<()> {
+        let cache_file = self.cache_dir.join("cache.json");
+
+        let entries_vec: Vec<(CacheKey, CacheEntry)> = entries
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+
+        let data = serde_json::to_string_pretty(&entries_vec).map_err(|e| {
+            Error::Internal(InternalError::assertion(format!(
+                "Failed to serialize cache: {e}"
+            )))
+        })?;
+
+        let mut file = fs::File::create(&cache_file).await.map_err(|e| {
+            Error::Internal(InternalError::assertion(format!(
+                "Failed to create cache file: {e}"
+            )))
+        })?;
+
+        file.write_all(data.as_bytes()).await.map_err(|e| {
+            Error::Internal(InternalError::assertion(format!(
+                "Failed to write cache: {e}"
+            )))
+        })?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl HashCache for DiskCache {
+    async fn get(&self, key: &CacheKey) -> Result<Option<HashResult>> {
+        let mut entries = self.entries.write().await;
+        let mut stats = self.stats.write().await;
+
+        if let Some(entry) = entries.get_mut(key) {
+            if let Some(expires_at) = entry.expires_at
+                && SystemTime::now() > expires_at
+            {
+                let removed = entries.remove(key);
+                if let Some(removed) = removed {
+                    stats.entry_count -= 1;
+                    stats.total_size_bytes = stats
+                        .total_size_bytes
+                        .saturating_sub(Self::estimate_entry_size(&removed));
+                }
+                stats.miss_count += 1;
+                self.save_to_disk(&entries).await?;
+                return Ok(None);
+            }
+
+            entry.last_accessed = SystemTime::now();
+            entry.access_count += 1;
+            stats.hit_count += 1;
+
+            Ok(Some(entry.hash_result.clone()))
+        } else {
+            stats.miss_count += 1;
+            Ok(None)
+        }
+    }
+
+    async fn put(&self, key: &CacheKey, value: &HashResult) -> Result<()> {
+        self.put_with_ttl(key, value, self.config.default_ttl)
+            .await
+    }
+
+    async fn put_with_ttl(&self, key: &CacheKey, value: &HashResult, ttl: Duration) -> Result<()> {
+        let mut entries = self.entries.write().await;
+        let mut stats = self.stats.write().await;
+
+        let now = SystemTime::now();
+        let expires_at = Some(now + ttl);
+
+        if let Some(old_entry) = entries.remove(key) {
+            stats.entry_count -= 1;
+            stats.total_size_bytes = stats
+                .total_size_bytes
+                .saturating_sub(Self::estimate_entry_size(&old_entry));
+        }
+
+        let entry = CacheEntry {
+            hash_result: value.clone(),
+            created_at: now,
+            last_accessed: now,
+            access_count: 0,
+            expires_at,
+        };
+
+        stats.total_size_bytes += Self::estimate_entry_size(&entry);
+        entries.insert(key.clone(), entry);
+        stats.entry_count += 1;
+
+        Self::evict_until_within_budget(&mut entries, &mut stats, self.budget_bytes());
+
+        self.save_to_disk(&entries).await?;
+
+        Ok(())
+    }
+
+    async fn invalidate(&self, key: &CacheKey) -> Result<()> {
+        let mut entries = self.entries.write().await;
+        let mut stats = self.stats.write().await;
+
+        if let Some(removed) = entries.remove(key) {
+            stats.entry_count -= 1;
+            stats.total_size_bytes = stats
+                .total_size_bytes
+                .saturating_sub(Self::estimate_entry_size(&removed));
+            self.save_to_disk(&entries).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn clear(&self) -> Result<()> {
+        let mut entries = self.entries.write().await;
+        let mut stats = self.stats.write().await;
+
+        entries.clear();
+        *stats = CacheStats::default();
+
+        self.save_to_disk(&entries).await?;
+
+        Ok(())
+    }
+
+    async fn stats(&self) -> Result<CacheStats> {
+        let stats = self.stats.read().await;
+        Ok(stats.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anidb_client_core::hashing::HashAlgorithm;
+    use std::path::Path;
+
+    fn test_key(name: &str) -> CacheKey {
+        CacheKey::new(Path::new(name), 1024, HashAlgorithm::MD5)
+    }
+
+    fn test_result(hash: &str) -> HashResult {
+        HashResult {
+            algorithm: HashAlgorithm::MD5,
+            hash: hash.to_string(),
+            input_size: 1024,
+            duration: Duration::from_millis(1),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_put_and_get_roundtrip() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cache = DiskCache::new(temp_dir.path().to_path_buf()).unwrap();
+
+        cache.put(&test_key("a"), &test_result("hash-a")).await.unwrap();
+
+        let result = cache.get(&test_key("a")).await.unwrap();
+        assert_eq!(result.unwrap().hash, "hash-a");
+
+        let stats = cache.stats().await.unwrap();
+        assert_eq!(stats.entry_count, 1);
+        assert_eq!(stats.hit_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_reports_miss_for_unknown_key() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cache = DiskCache::new(temp_dir.path().to_path_buf()).unwrap();
+
+        assert!(cache.get(&test_key("missing")).await.unwrap().is_none());
+
+        let stats = cache.stats().await.unwrap();
+        assert_eq!(stats.miss_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_eviction_keeps_cache_within_disk_budget() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cache = DiskCache::with_config(
+            temp_dir.path().to_path_buf(),
+            DiskCacheConfig {
+                disk_usage_kb: 0, // Any single entry already exceeds a zero budget.
+                default_ttl: Duration::from_secs(3600),
+            },
+        )
+        .unwrap();
+
+        cache.put(&test_key("a"), &test_result("hash-a")).await.unwrap();
+        // "a" is now the least-recently-used; touching it promotes it ahead of "b".
+        cache.get(&test_key("a")).await.unwrap();
+        cache.put(&test_key("b"), &test_result("hash-b")).await.unwrap();
+
+        let stats = cache.stats().await.unwrap();
+        assert_eq!(stats.entry_count, 1);
+        assert!(stats.eviction_count >= 1);
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_removes_entry() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cache = DiskCache::new(temp_dir.path().to_path_buf()).unwrap();
+
+        cache.put(&test_key("a"), &test_result("hash-a")).await.unwrap();
+        cache.invalidate(&test_key("a")).await.unwrap();
+
+        assert!(cache.get(&test_key("a")).await.unwrap().is_none());
+        let stats = cache.stats().await.unwrap();
+        assert_eq!(stats.entry_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_clear_resets_entries_and_stats() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cache = DiskCache::new(temp_dir.path().to_path_buf()).unwrap();
+
+        cache.put(&test_key("a"), &test_result("hash-a")).await.unwrap();
+        cache.clear().await.unwrap();
+
+        let stats = cache.stats().await.unwrap();
+        assert_eq!(stats.entry_count, 0);
+        assert!(cache.get(&test_key("a")).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_entries_persist_across_cache_instances() {
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        let cache = DiskCache::new(temp_dir.path().to_path_buf()).unwrap();
+        cache.put(&test_key("a"), &test_result("hash-a")).await.unwrap();
+        drop(cache);
+
+        let reloaded = DiskCache::new(temp_dir.path().to_path_buf()).unwrap();
+        let result = reloaded.get(&test_key("a")).await.unwrap();
+        assert_eq!(result.unwrap().hash, "hash-a");
+    }
+}
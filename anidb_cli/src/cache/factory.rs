@@ -3,9 +3,13 @@
 //! This module provides a factory pattern for creating cache instances
 //! based on configuration.
 
+use crate::cache::disk_cache::DiskCacheConfig;
+use crate::cache::fingerprint::FingerprintConfig;
 use crate::cache::memory_cache::MemoryCacheConfig;
 use crate::cache::traits::HashCache;
-use crate::cache::{file_cache::FileCache, memory_cache::MemoryCache, noop_cache::NoOpCache};
+use crate::cache::{
+    disk_cache::DiskCache, file_cache::FileCache, memory_cache::MemoryCache, noop_cache::NoOpCache,
+};
 use crate::paths;
 use anidb_client_core::error::Result;
 use std::path::PathBuf;
@@ -23,6 +27,11 @@ pub enum CacheConfig {
     },
     /// Memory-based cache with configuration
     Memory(MemoryCacheConfig),
+    /// Disk-backed cache bounded by a size budget, with LRU eviction
+    Disk {
+        cache_dir: PathBuf,
+        disk_config: DiskCacheConfig,
+    },
     /// No-operation cache (no caching)
     NoOp,
     /// Layered cache (memory + file fallback)
@@ -65,6 +74,13 @@ impl CacheFactory {
                 let cache = MemoryCache::with_config(config);
                 Ok(Arc::new(cache))
             }
+            CacheConfig::Disk {
+                cache_dir,
+                disk_config,
+            } => {
+                let cache = DiskCache::with_config(cache_dir, disk_config)?;
+                Ok(Arc::new(cache))
+            }
             CacheConfig::NoOp => Ok(Arc::new(NoOpCache::new())),
             CacheConfig::Layered {
                 memory_config,
@@ -87,16 +103,54 @@ impl CacheFactory {
         })
     }
 
+    /// Create a file-based cache bounded to at most `max_entries` entries, evicting
+    /// least-recently-used entries past that capacity
+    pub fn file_with_capacity(cache_dir: PathBuf, max_entries: usize) -> Result<Arc<dyn HashCache>> {
+        Self::create(CacheConfig::File {
+            cache_dir,
+            max_entries: Some(max_entries),
+        })
+    }
+
     /// Create a memory-based cache
     #[allow(dead_code)]
     pub fn memory() -> Result<Arc<dyn HashCache>> {
         Self::create(CacheConfig::Memory(MemoryCacheConfig::default()))
     }
 
+    /// Create a memory-based cache bounded to at most `max_entries` entries, evicting
+    /// least-recently-used entries past that capacity
+    #[allow(dead_code)]
+    pub fn memory_with_capacity(max_entries: usize) -> Result<Arc<dyn HashCache>> {
+        Self::create(CacheConfig::Memory(MemoryCacheConfig {
+            max_entries: Some(max_entries),
+            ..MemoryCacheConfig::default()
+        }))
+    }
+
+    // Note: `SqliteHashCache` (cache/sqlite_cache.rs) predates the current `HashCache` trait
+    // and doesn't implement it yet (see the TODO in that file), so it has no
+    // `CacheConfig` variant and can't take a capacity bound here until that migration happens.
+
+    /// Create a disk-backed cache with a default ~1 GiB budget
+    pub fn disk(cache_dir: PathBuf) -> Result<Arc<dyn HashCache>> {
+        Self::create(CacheConfig::Disk {
+            cache_dir,
+            disk_config: DiskCacheConfig::default(),
+        })
+    }
+
     /// Create a no-op cache
     pub fn noop() -> Result<Arc<dyn HashCache>> {
         Self::create(CacheConfig::NoOp)
     }
+
+    /// Build a content-fingerprint config sampling `sample_bytes` total from the head and
+    /// tail of each file, for use with [`crate::cache::service::HashCacheService::with_fingerprinting`]
+    #[allow(dead_code)]
+    pub fn fingerprint_config(sample_bytes: u64) -> FingerprintConfig {
+        FingerprintConfig { sample_bytes }
+    }
 }
 
 /// Layered cache implementation (L1: Memory, L2: File)
@@ -182,6 +236,7 @@ impl HashCache for LayeredCache {
             hit_count: l1_stats.hit_count + l2_stats.hit_count,
             miss_count: l1_stats.miss_count, // Only count L1 misses
             total_size_bytes: l1_stats.total_size_bytes + l2_stats.total_size_bytes,
+            eviction_count: l1_stats.eviction_count + l2_stats.eviction_count,
         })
     }
 }
@@ -48,6 +48,7 @@ impl FileCache {
                 hit_count: 0,
                 miss_count: 0,
                 total_size_bytes: 0,
+                eviction_count: 0,
             })),
             max_entries: None,
         })
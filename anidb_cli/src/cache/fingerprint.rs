@@ -0,0 +1,134 @@
+//! Content-fingerprint sampling for cache keys
+//!
+//! [`CacheKey`](crate::cache::CacheKey) is normally `(file_path, file_size, algorithm)`, so a
+//! file edited in place without changing length returns a stale cached hash result. This
+//! module computes a fast, optional fingerprint over a bounded sample of the file (its first
+//! and last N KiB, plus size and mtime) that callers can fold into the key instead, so an
+//! in-place edit changes the key and the stale entry is simply never found.
+//!
+//! SipHash-2-4 is used because it's fast and collision-resistant enough for this
+//! invalidation check without paying for a cryptographic digest over the whole file.
+
+use anidb_client_core::error::Result;
+use siphasher::sip::SipHasher24;
+use std::fs::File;
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+use std::sync::OnceLock;
+use std::time::SystemTime;
+
+/// Total bytes sampled by default: 32 KiB from the head and 32 KiB from the tail
+pub const DEFAULT_FINGERPRINT_SAMPLE_BYTES: u64 = 64 * 1024;
+
+/// Configuration for content-fingerprint cache keys
+///
+/// `sample_bytes` is the total read budget, split evenly between the head and tail of the
+/// file; `0` means fingerprinting is disabled (the default, matching pre-fingerprint
+/// `CacheKey` behavior).
+#[derive(Debug, Clone, Copy)]
+pub struct FingerprintConfig {
+    pub sample_bytes: u64,
+}
+
+impl Default for FingerprintConfig {
+    fn default() -> Self {
+        Self { sample_bytes: 0 }
+    }
+}
+
+impl FingerprintConfig {
+    /// Enable fingerprinting with the default 64 KiB sample budget
+    pub fn enabled() -> Self {
+        Self {
+            sample_bytes: DEFAULT_FINGERPRINT_SAMPLE_BYTES,
+        }
+    }
+}
+
+/// A pair of SipHash keys generated once per process, so fingerprints are stable for the
+/// lifetime of this process but not predictable or comparable across runs
+fn sip_keys() -> (u64, u64) {
+    static KEYS: OnceLock<(u64, u64)> = OnceLock::new();
+    *KEYS.get_or_init(|| {
+        let random = std::collections::hash_map::RandomState::new();
+        (random.build_hasher().finish(), random.build_hasher().finish())
+    })
+}
+
+/// Compute a SipHash-2-4 fingerprint over `file_size`, the file's mtime (if available), and
+/// up to `sample_bytes` total bytes sampled from the start and end of the file at `path`
+///
+/// Reads are bounded by `sample_bytes`, so this stays far cheaper than rehashing the whole
+/// file with MD5/SHA1/etc.
+pub fn compute(path: &Path, file_size: u64, sample_bytes: u64) -> Result<u64> {
+    let (k0, k1) = sip_keys();
+    let mut hasher = SipHasher24::new_with_keys(k0, k1);
+
+    file_size.hash(&mut hasher);
+    if let Ok(mtime) = std::fs::metadata(path).and_then(|m| m.modified())
+        && let Ok(since_epoch) = mtime.duration_since(SystemTime::UNIX_EPOCH)
+    {
+        since_epoch.as_nanos().hash(&mut hasher);
+    }
+
+    let mut file = File::open(path)?;
+    let half = (sample_bytes / 2).max(1);
+
+    let head_len = half.min(file_size) as usize;
+    let mut head_buf = vec![0u8; head_len];
+    file.read_exact(&mut head_buf)?;
+    hasher.write(&head_buf);
+
+    let tail_len = half.min(file_size - head_len as u64) as usize;
+    if tail_len > 0 {
+        file.seek(SeekFrom::End(-(tail_len as i64)))?;
+        let mut tail_buf = vec![0u8; tail_len];
+        file.read_exact(&mut tail_buf)?;
+        hasher.write(&tail_buf);
+    }
+
+    Ok(hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    fn write_file(contents: &[u8]) -> NamedTempFile {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(contents).unwrap();
+        file.flush().unwrap();
+        file
+    }
+
+    #[test]
+    fn test_fingerprint_changes_on_in_place_edit_of_same_length() {
+        let file = write_file(b"aaaaaaaaaa");
+        let before = compute(file.path(), 10, 64 * 1024).unwrap();
+
+        std::fs::write(file.path(), b"bbbbbbbbbb").unwrap();
+        let after = compute(file.path(), 10, 64 * 1024).unwrap();
+
+        assert_ne!(before, after);
+    }
+
+    #[test]
+    fn test_fingerprint_stable_for_unchanged_file() {
+        let file = write_file(b"stable contents");
+        let first = compute(file.path(), 15, 64 * 1024).unwrap();
+        let second = compute(file.path(), 15, 64 * 1024).unwrap();
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_fingerprint_handles_file_smaller_than_sample() {
+        let file = write_file(b"tiny");
+        let fingerprint = compute(file.path(), 4, 64 * 1024);
+
+        assert!(fingerprint.is_ok());
+    }
+}
@@ -37,9 +37,164 @@ impl Default for MemoryCacheConfig {
     }
 }
 
+/// A node in the intrusive LRU recency list
+///
+/// The list is threaded through `CacheKey`s rather than raw pointers, so it can live
+/// directly inside the lookup map without any unsafe code: `prev`/`next` are the
+/// neighboring keys, and moving a node to the front on a hit or dropping the tail on
+/// eviction is just a handful of map lookups, not a scan of every entry.
+struct LruNode {
+    entry: CacheEntry,
+    prev: Option<CacheKey>,
+    next: Option<CacheKey>,
+}
+
+/// A `CacheKey -> CacheEntry` map with an embedded doubly-linked recency list, so the
+/// most- and least-recently-used entries are always O(1) to find instead of requiring a
+/// `min_by_key` scan over every entry on each eviction
+struct LruIndex {
+    nodes: HashMap<CacheKey, LruNode>,
+    /// Most recently used key
+    head: Option<CacheKey>,
+    /// Least recently used key
+    tail: Option<CacheKey>,
+}
+
+impl LruIndex {
+    fn new() -> Self {
+        Self {
+            nodes: HashMap::new(),
+            head: None,
+            tail: None,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Look up `key`, promoting it to most-recently-used on a hit
+    fn get_mut(&mut self, key: &CacheKey) -> Option<&mut CacheEntry> {
+        if !self.nodes.contains_key(key) {
+            return None;
+        }
+        self.move_to_front(key);
+        self.nodes.get_mut(key).map(|node| &mut node.entry)
+    }
+
+    /// Insert or replace `key`, making it most-recently-used; returns the displaced entry
+    fn insert(&mut self, key: CacheKey, entry: CacheEntry) -> Option<CacheEntry> {
+        let displaced = self.remove(&key);
+        self.push_front(key, entry);
+        displaced
+    }
+
+    /// Remove `key` from both the map and the recency list
+    fn remove(&mut self, key: &CacheKey) -> Option<CacheEntry> {
+        let node = self.nodes.remove(key)?;
+        self.unlink(node.prev.clone(), node.next.clone());
+        Some(node.entry)
+    }
+
+    /// Evict and return the least-recently-used entry
+    fn pop_back(&mut self) -> Option<(CacheKey, CacheEntry)> {
+        let key = self.tail.clone()?;
+        let entry = self.remove(&key)?;
+        Some((key, entry))
+    }
+
+    fn clear(&mut self) {
+        self.nodes.clear();
+        self.head = None;
+        self.tail = None;
+    }
+
+    /// Drop every entry for which `keep` returns `false`
+    fn retain(&mut self, mut keep: impl FnMut(&CacheKey, &CacheEntry) -> bool) {
+        let to_remove: Vec<CacheKey> = self
+            .nodes
+            .iter()
+            .filter(|(key, node)| !keep(key, &node.entry))
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        for key in to_remove {
+            self.remove(&key);
+        }
+    }
+
+    fn push_front(&mut self, key: CacheKey, entry: CacheEntry) {
+        let old_head = self.head.clone();
+        self.nodes.insert(
+            key.clone(),
+            LruNode {
+                entry,
+                prev: None,
+                next: old_head.clone(),
+            },
+        );
+
+        if let Some(old_head_key) = &old_head {
+            if let Some(old_head_node) = self.nodes.get_mut(old_head_key) {
+                old_head_node.prev = Some(key.clone());
+            }
+        } else {
+            // List was empty: the new node is also the tail.
+            self.tail = Some(key.clone());
+        }
+
+        self.head = Some(key);
+    }
+
+    fn move_to_front(&mut self, key: &CacheKey) {
+        if self.head.as_ref() == Some(key) {
+            return; // Already most-recently-used.
+        }
+
+        let Some(node) = self.nodes.get(key) else {
+            return;
+        };
+        let (prev, next) = (node.prev.clone(), node.next.clone());
+        self.unlink(prev, next);
+
+        let old_head = self.head.clone();
+        if let Some(node) = self.nodes.get_mut(key) {
+            node.prev = None;
+            node.next = old_head.clone();
+        }
+        if let Some(old_head_key) = &old_head {
+            if let Some(old_head_node) = self.nodes.get_mut(old_head_key) {
+                old_head_node.prev = Some(key.clone());
+            }
+        }
+        self.head = Some(key.clone());
+    }
+
+    /// Stitch `prev` and `next` together, skipping over the node currently between them
+    fn unlink(&mut self, prev: Option<CacheKey>, next: Option<CacheKey>) {
+        match &prev {
+            Some(prev_key) => {
+                if let Some(prev_node) = self.nodes.get_mut(prev_key) {
+                    prev_node.next = next.clone();
+                }
+            }
+            None => self.head = next.clone(),
+        }
+
+        match &next {
+            Some(next_key) => {
+                if let Some(next_node) = self.nodes.get_mut(next_key) {
+                    next_node.prev = prev.clone();
+                }
+            }
+            None => self.tail = prev.clone(),
+        }
+    }
+}
+
 /// Memory-based cache for storing hash results
 pub struct MemoryCache {
-    entries: Arc<RwLock<HashMap<CacheKey, CacheEntry>>>,
+    entries: Arc<RwLock<LruIndex>>,
     stats: Arc<RwLock<CacheStats>>,
     config: MemoryCacheConfig,
     shutdown: Arc<RwLock<bool>>,
@@ -54,7 +209,7 @@ impl MemoryCache {
     /// Create a new memory cache with custom configuration
     pub fn with_config(config: MemoryCacheConfig) -> Self {
         let cache = Self {
-            entries: Arc::new(RwLock::new(HashMap::new())),
+            entries: Arc::new(RwLock::new(LruIndex::new())),
             stats: Arc::new(RwLock::new(CacheStats::default())),
             config: config.clone(),
             shutdown: Arc::new(RwLock::new(false)),
@@ -97,42 +252,38 @@ impl MemoryCache {
     }
 
     /// Perform LRU eviction to stay within limits
-    async fn evict_if_needed(
-        &self,
-        entries: &mut HashMap<CacheKey, CacheEntry>,
-        stats: &mut CacheStats,
-    ) {
+    ///
+    /// Both limits evict from the tail of the recency list, which is always the
+    /// least-recently-used entry, so this never needs to scan the rest of the cache.
+    async fn evict_if_needed(&self, entries: &mut LruIndex, stats: &mut CacheStats) {
         // Check entry count limit
         if let Some(max) = self.config.max_entries {
             while entries.len() >= max {
-                // Find and remove least recently used entry
-                if let Some(oldest_key) = entries
-                    .iter()
-                    .min_by_key(|(_, entry)| entry.last_accessed)
-                    .map(|(k, _)| k.clone())
-                {
-                    entries.remove(&oldest_key);
-                    stats.entry_count -= 1;
+                match entries.pop_back() {
+                    Some((key, removed)) => {
+                        stats.entry_count -= 1;
+                        stats.eviction_count += 1;
+                        let estimated_size = Self::estimate_entry_size(&key, &removed.hash_result);
+                        stats.total_size_bytes =
+                            stats.total_size_bytes.saturating_sub(estimated_size);
+                    }
+                    None => break,
                 }
             }
         }
 
         // Check memory limit
         if let Some(max_bytes) = self.config.max_memory_bytes {
-            while stats.total_size_bytes > max_bytes && !entries.is_empty() {
-                // Remove least recently used entry
-                if let Some(oldest_key) = entries
-                    .iter()
-                    .min_by_key(|(_, entry)| entry.last_accessed)
-                    .map(|(k, _)| k.clone())
-                    && let Some(removed) = entries.remove(&oldest_key)
-                {
-                    stats.entry_count -= 1;
-                    // Estimate memory usage reduction
-                    let estimated_size = std::mem::size_of::<CacheKey>() as u64
-                        + std::mem::size_of::<CacheEntry>() as u64
-                        + removed.hash_result.hash.len() as u64;
-                    stats.total_size_bytes = stats.total_size_bytes.saturating_sub(estimated_size);
+            while stats.total_size_bytes > max_bytes {
+                match entries.pop_back() {
+                    Some((key, removed)) => {
+                        stats.entry_count -= 1;
+                        stats.eviction_count += 1;
+                        let estimated_size = Self::estimate_entry_size(&key, &removed.hash_result);
+                        stats.total_size_bytes =
+                            stats.total_size_bytes.saturating_sub(estimated_size);
+                    }
+                    None => break,
                 }
             }
         }
@@ -189,19 +340,20 @@ impl HashCache for MemoryCache {
         let mut entries = self.entries.write().await;
         let mut stats = self.stats.write().await;
 
-        // Evict if needed
-        self.evict_if_needed(&mut entries, &mut stats).await;
-
-        let now = SystemTime::now();
-        let expires_at = Some(now + ttl);
-
-        // Remove old entry if it exists
+        // Remove old entry if it exists, so updating an already-present key doesn't count
+        // against `max_entries`/`max_memory_bytes` and spuriously evict an unrelated entry.
         if let Some(old_entry) = entries.remove(key) {
             let old_size = Self::estimate_entry_size(key, &old_entry.hash_result);
             stats.total_size_bytes = stats.total_size_bytes.saturating_sub(old_size);
             stats.entry_count -= 1;
         }
 
+        // Evict if still over capacity now that the (possibly stale) entry for `key` is gone
+        self.evict_if_needed(&mut entries, &mut stats).await;
+
+        let now = SystemTime::now();
+        let expires_at = Some(now + ttl);
+
         let entry = CacheEntry {
             hash_result: value.clone(),
             created_at: now,
@@ -263,3 +415,98 @@ impl Default for MemoryCache {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anidb_client_core::hashing::HashAlgorithm;
+    use std::path::Path;
+
+    fn test_key(name: &str) -> CacheKey {
+        CacheKey::new(Path::new(name), 1024, HashAlgorithm::MD5)
+    }
+
+    fn test_result(hash: &str) -> HashResult {
+        HashResult {
+            algorithm: HashAlgorithm::MD5,
+            hash: hash.to_string(),
+            input_size: 1024,
+            duration: Duration::from_millis(1),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_lru_eviction_drops_least_recently_used() {
+        let cache = MemoryCache::with_config(MemoryCacheConfig {
+            max_entries: Some(2),
+            max_memory_bytes: None,
+            default_ttl: Duration::from_secs(3600),
+            cleanup_interval: Duration::from_secs(3600),
+        });
+
+        cache.put(&test_key("a"), &test_result("hash-a")).await.unwrap();
+        cache.put(&test_key("b"), &test_result("hash-b")).await.unwrap();
+        // "a" is now the least-recently-used; touching it promotes it ahead of "b".
+        cache.get(&test_key("a")).await.unwrap();
+        cache.put(&test_key("c"), &test_result("hash-c")).await.unwrap();
+
+        assert!(cache.get(&test_key("a")).await.unwrap().is_some());
+        assert!(cache.get(&test_key("b")).await.unwrap().is_none());
+        assert!(cache.get(&test_key("c")).await.unwrap().is_some());
+
+        let stats = cache.stats().await.unwrap();
+        assert_eq!(stats.entry_count, 2);
+        assert_eq!(stats.eviction_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_updating_existing_key_at_capacity_does_not_evict_others() {
+        let cache = MemoryCache::with_config(MemoryCacheConfig {
+            max_entries: Some(2),
+            max_memory_bytes: None,
+            default_ttl: Duration::from_secs(3600),
+            cleanup_interval: Duration::from_secs(3600),
+        });
+
+        cache.put(&test_key("a"), &test_result("hash-a")).await.unwrap();
+        cache.put(&test_key("b"), &test_result("hash-b")).await.unwrap();
+        // Refreshing "b" shouldn't grow the entry count, so it must not evict "a".
+        cache.put(&test_key("b"), &test_result("hash-b2")).await.unwrap();
+
+        assert!(cache.get(&test_key("a")).await.unwrap().is_some());
+        assert_eq!(
+            cache.get(&test_key("b")).await.unwrap().unwrap().hash,
+            "hash-b2"
+        );
+
+        let stats = cache.stats().await.unwrap();
+        assert_eq!(stats.entry_count, 2);
+        assert_eq!(stats.eviction_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_stats_track_hits_and_misses() {
+        let cache = MemoryCache::new();
+
+        cache.put(&test_key("a"), &test_result("hash-a")).await.unwrap();
+        cache.get(&test_key("a")).await.unwrap();
+        cache.get(&test_key("missing")).await.unwrap();
+
+        let stats = cache.stats().await.unwrap();
+        assert_eq!(stats.hit_count, 1);
+        assert_eq!(stats.miss_count, 1);
+        assert_eq!(stats.eviction_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_clear_resets_entries_and_stats() {
+        let cache = MemoryCache::new();
+
+        cache.put(&test_key("a"), &test_result("hash-a")).await.unwrap();
+        cache.clear().await.unwrap();
+
+        let stats = cache.stats().await.unwrap();
+        assert_eq!(stats.entry_count, 0);
+        assert!(cache.get(&test_key("a")).await.unwrap().is_none());
+    }
+}
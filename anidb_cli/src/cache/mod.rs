@@ -16,20 +16,45 @@ use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
 /// Cache key for identifying entries
+///
+/// `fingerprint` is an optional content fingerprint (see [`fingerprint::compute`]) over a
+/// bounded sample of the file; since it participates in `Hash`/`Eq`, a file edited in place
+/// without changing size simply produces a key that doesn't match any cached entry instead
+/// of returning a stale result.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct CacheKey {
     pub file_path: PathBuf,
     pub file_size: u64,
     pub algorithm: HashAlgorithm,
+    /// Missing in `cache.json` files written before fingerprinting was added, so this
+    /// must default to `None` rather than fail to deserialize on first load after upgrading.
+    #[serde(default)]
+    pub fingerprint: Option<u64>,
 }
 
 impl CacheKey {
-    /// Create a new cache key
+    /// Create a new cache key with no content fingerprint
     pub fn new(file_path: &Path, file_size: u64, algorithm: HashAlgorithm) -> Self {
         Self {
             file_path: file_path.to_path_buf(),
             file_size,
             algorithm,
+            fingerprint: None,
+        }
+    }
+
+    /// Create a cache key carrying a content fingerprint, see [`fingerprint::compute`]
+    pub fn with_fingerprint(
+        file_path: &Path,
+        file_size: u64,
+        algorithm: HashAlgorithm,
+        fingerprint: u64,
+    ) -> Self {
+        Self {
+            file_path: file_path.to_path_buf(),
+            file_size,
+            algorithm,
+            fingerprint: Some(fingerprint),
         }
     }
 }
@@ -51,11 +76,14 @@ pub struct CacheStats {
     pub hit_count: u64,
     pub miss_count: u64,
     pub total_size_bytes: u64,
+    pub eviction_count: u64,
 }
 
 // Re-export sub-modules
+pub mod disk_cache;
 pub mod factory;
 pub mod file_cache;
+pub mod fingerprint;
 pub mod identification_service;
 pub mod memory_cache;
 pub mod noop_cache;
@@ -64,4 +92,5 @@ pub mod sqlite_cache;
 pub mod traits;
 
 // Re-export commonly used types
+pub use fingerprint::FingerprintConfig;
 pub use identification_service::IdentificationCacheService;
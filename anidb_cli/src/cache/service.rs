@@ -4,6 +4,7 @@
 //! on top of the core AniDBClient, transparently handling cache lookups
 //! and storage for file processing operations.
 
+use crate::cache::fingerprint::{self, FingerprintConfig};
 use crate::cache::traits::HashCache;
 use crate::cache::{CacheKey, CacheStats};
 use anidb_client_core::{
@@ -28,6 +29,8 @@ pub struct HashCacheService {
     cache: Arc<dyn HashCache>,
     /// Enable verbose logging
     verbose: bool,
+    /// Content-fingerprint sampling, disabled unless set via `with_fingerprinting`
+    fingerprint_config: Option<FingerprintConfig>,
 }
 
 impl HashCacheService {
@@ -42,6 +45,7 @@ impl HashCacheService {
             client,
             cache,
             verbose: false,
+            fingerprint_config: None,
         }
     }
 
@@ -52,6 +56,54 @@ impl HashCacheService {
         self
     }
 
+    /// Enable content-fingerprint cache keys, so editing a file in place without changing
+    /// its size invalidates the cache instead of returning a stale result
+    #[allow(dead_code)]
+    pub fn with_fingerprinting(mut self, config: FingerprintConfig) -> Self {
+        self.fingerprint_config = Some(config);
+        self
+    }
+
+    /// Sample a content fingerprint for `file_path` once per `(file_path, file_size)`,
+    /// reading the bounded head/tail sample from disk at most once regardless of how many
+    /// algorithms the caller later builds cache keys for. Returns `None` when fingerprinting
+    /// is disabled.
+    async fn sample_fingerprint(&self, file_path: &Path, file_size: u64) -> Result<Option<u64>> {
+        let Some(config) = self.fingerprint_config.filter(|c| c.sample_bytes > 0) else {
+            return Ok(None);
+        };
+
+        let path = file_path.to_path_buf();
+        let fingerprint = tokio::task::spawn_blocking(move || {
+            fingerprint::compute(&path, file_size, config.sample_bytes)
+        })
+        .await
+        .map_err(|e| {
+            anidb_client_core::Error::Internal(anidb_client_core::error::InternalError::assertion(
+                format!("fingerprint task panicked: {e}"),
+            ))
+        })??;
+
+        Ok(Some(fingerprint))
+    }
+
+    /// Build the cache key for `file_path` from an already-sampled fingerprint (see
+    /// [`Self::sample_fingerprint`]); purely a data construction, no I/O
+    fn cache_key(
+        &self,
+        file_path: &Path,
+        file_size: u64,
+        algorithm: HashAlgorithm,
+        fingerprint: Option<u64>,
+    ) -> CacheKey {
+        match fingerprint {
+            Some(fingerprint) => {
+                CacheKey::with_fingerprint(file_path, file_size, algorithm, fingerprint)
+            }
+            None => CacheKey::new(file_path, file_size, algorithm),
+        }
+    }
+
     /// Process a file with cache support
     ///
     /// This method checks the cache first if caching is enabled in the options,
@@ -80,6 +132,7 @@ impl HashCacheService {
             ))
         })?;
         let file_size = metadata.len();
+        let fingerprint = self.sample_fingerprint(file_path, file_size).await?;
 
         // Check cache for each requested algorithm if caching is enabled
         if use_cache {
@@ -87,7 +140,7 @@ impl HashCacheService {
             let mut missing_algorithms = Vec::new();
 
             for algorithm in options.algorithms() {
-                let cache_key = CacheKey::new(file_path, file_size, *algorithm);
+                let cache_key = self.cache_key(file_path, file_size, *algorithm, fingerprint);
 
                 if let Ok(Some(hash_result)) = self.cache.get(&cache_key).await {
                     if self.verbose {
@@ -122,7 +175,7 @@ impl HashCacheService {
 
                 // Store newly calculated hashes in cache
                 for (algorithm, hash) in &result.hashes {
-                    let cache_key = CacheKey::new(file_path, file_size, *algorithm);
+                    let cache_key = self.cache_key(file_path, file_size, *algorithm, fingerprint);
                     let hash_result = anidb_client_core::hashing::HashResult {
                         hash: hash.clone(),
                         algorithm: *algorithm,
@@ -158,7 +211,7 @@ impl HashCacheService {
         // Store result in cache if caching is enabled
         if use_cache {
             for (algorithm, hash) in &result.hashes {
-                let cache_key = CacheKey::new(file_path, file_size, *algorithm);
+                let cache_key = self.cache_key(file_path, file_size, *algorithm, fingerprint);
                 let hash_result = anidb_client_core::hashing::HashResult {
                     hash: hash.clone(),
                     algorithm: *algorithm,
@@ -194,6 +247,7 @@ impl HashCacheService {
             ))
         })?;
         let file_size = metadata.len();
+        let fingerprint = self.sample_fingerprint(file_path, file_size).await?;
 
         // Check cache for each requested algorithm if caching is enabled
         if use_cache {
@@ -201,7 +255,7 @@ impl HashCacheService {
             let mut missing_algorithms = Vec::new();
 
             for algorithm in options.algorithms() {
-                let cache_key = CacheKey::new(file_path, file_size, *algorithm);
+                let cache_key = self.cache_key(file_path, file_size, *algorithm, fingerprint);
 
                 if let Ok(Some(hash_result)) = self.cache.get(&cache_key).await {
                     if self.verbose {
@@ -239,7 +293,7 @@ impl HashCacheService {
 
                 // Store newly calculated hashes in cache
                 for (algorithm, hash) in &result.hashes {
-                    let cache_key = CacheKey::new(file_path, file_size, *algorithm);
+                    let cache_key = self.cache_key(file_path, file_size, *algorithm, fingerprint);
                     let hash_result = anidb_client_core::hashing::HashResult {
                         hash: hash.clone(),
                         algorithm: *algorithm,
@@ -278,7 +332,7 @@ impl HashCacheService {
         // Store result in cache if caching is enabled
         if use_cache {
             for (algorithm, hash) in &result.hashes {
-                let cache_key = CacheKey::new(file_path, file_size, *algorithm);
+                let cache_key = self.cache_key(file_path, file_size, *algorithm, fingerprint);
                 let hash_result = anidb_client_core::hashing::HashResult {
                     hash: hash.clone(),
                     algorithm: *algorithm,
@@ -377,7 +431,10 @@ impl HashCacheService {
         file_size: u64,
         algorithm: HashAlgorithm,
     ) -> Result<()> {
-        let cache_key = CacheKey::new(file_path, file_size, algorithm);
+        // Must use the same fingerprint-aware key construction as get/put, or this silently
+        // misses entries stored with a fingerprint once `with_fingerprinting` is enabled.
+        let fingerprint = self.sample_fingerprint(file_path, file_size).await?;
+        let cache_key = self.cache_key(file_path, file_size, algorithm, fingerprint);
         self.cache.invalidate(&cache_key).await
     }
 }
@@ -71,6 +71,19 @@ enum Commands {
         /// Bypass cache and recalculate hashes
         #[arg(long)]
         no_cache: bool,
+
+        /// Bound the file cache to at most this many entries, evicting least-recently-used
+        /// entries past that capacity (unbounded by default)
+        #[arg(long, value_name = "N")]
+        cache_max_entries: Option<usize>,
+
+        /// Cache backend to use
+        #[arg(long, value_enum, default_value = "file")]
+        cache_backend: CacheBackendArg,
+
+        /// Disk cache budget in MiB, only used with `--cache-backend disk` (~1024 MiB by default)
+        #[arg(long, value_name = "MB")]
+        cache_disk_budget_mb: Option<u64>,
     },
 
     /// Identify file(s) via AniDB
@@ -183,6 +196,14 @@ enum OutputFormat {
     Csv,
 }
 
+#[derive(clap::ValueEnum, Clone, Debug)]
+enum CacheBackendArg {
+    /// Unbounded-by-default file cache, one entry per file and hash algorithm
+    File,
+    /// Size-budgeted cache with LRU eviction once the budget is exceeded
+    Disk,
+}
+
 impl From<HashAlgorithmArg> for Vec<HashAlgorithm> {
     fn from(arg: HashAlgorithmArg) -> Self {
         match arg {
@@ -232,6 +253,9 @@ async fn main() -> Result<()> {
             recursive,
             no_progress,
             no_cache,
+            cache_max_entries,
+            cache_backend,
+            cache_disk_budget_mb,
         } => {
             hash_command(
                 config,
@@ -244,6 +268,9 @@ async fn main() -> Result<()> {
                 recursive,
                 no_progress,
                 no_cache,
+                cache_max_entries,
+                cache_backend,
+                cache_disk_budget_mb,
             )
             .await?;
         }
@@ -331,6 +358,9 @@ async fn hash_command(
     recursive: bool,
     no_progress: bool,
     no_cache: bool,
+    cache_max_entries: Option<usize>,
+    cache_backend: CacheBackendArg,
+    cache_disk_budget_mb: Option<u64>,
 ) -> Result<()> {
     use anidb_cli::file_discovery::{FileDiscovery, FileDiscoveryOptions};
 
@@ -385,8 +415,22 @@ async fn hash_command(
         let cache_dir = dirs::data_dir()
             .map(|d| d.join("anidb/cache"))
             .unwrap_or_else(|| std::path::PathBuf::from(".anidb/cache"));
-        // Use file cache with the configured cache directory
-        CacheFactory::file(cache_dir)?
+        match cache_backend {
+            CacheBackendArg::File => match cache_max_entries {
+                Some(max_entries) => CacheFactory::file_with_capacity(cache_dir, max_entries)?,
+                None => CacheFactory::file(cache_dir)?,
+            },
+            CacheBackendArg::Disk => match cache_disk_budget_mb {
+                Some(budget_mb) => CacheFactory::create(crate::cache::factory::CacheConfig::Disk {
+                    cache_dir,
+                    disk_config: crate::cache::disk_cache::DiskCacheConfig {
+                        disk_usage_kb: budget_mb.saturating_mul(1024),
+                        ..Default::default()
+                    },
+                })?,
+                None => CacheFactory::disk(cache_dir)?,
+            },
+        }
     };
 
     // Create the cache service
@@ -0,0 +1,251 @@
+//! Chainable incremental buffer writer for zero-allocation command/result assembly
+//!
+//! `optimized_string_alloc` and `create_hash_result_zero_copy` each hand-roll the same
+//! `libc::malloc` + `ptr::copy_nonoverlapping` + null-terminate sequence around a handful
+//! of fields. [`BufMutWriter`] offers that sequence as one reusable type, though neither of
+//! those two functions is built on it: callers append fields with `put_*` as they
+//! go (backed by a pooled buffer from [`crate::ffi_string_pool`] rather than a fresh
+//! allocation), then materialize the whole thing into a single `malloc`-ed, NUL-terminated
+//! C string once at the end.
+
+use std::mem::MaybeUninit;
+use std::os::raw::c_char;
+use std::ptr;
+
+/// Growable, pooled byte buffer with a `bytes::BufMut`-like chainable write surface
+pub struct BufMutWriter {
+    buffer: Vec<u8>,
+}
+
+impl Default for BufMutWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BufMutWriter {
+    /// Default starting capacity, sized for a typical protocol command or hash field
+    const DEFAULT_CAPACITY: usize = 64;
+
+    /// Create a writer backed by a pooled buffer of default capacity
+    pub fn new() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+
+    /// Create a writer backed by a pooled buffer with at least `capacity` bytes of room
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: crate::ffi_string_pool::pooled_buffer(capacity),
+        }
+    }
+
+    /// Number of bytes written so far
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Whether nothing has been written yet
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Bytes of spare capacity available before the next write would need to reallocate
+    pub fn remaining_mut(&self) -> usize {
+        self.buffer.capacity() - self.buffer.len()
+    }
+
+    /// Append raw bytes
+    pub fn put_slice(&mut self, data: &[u8]) {
+        self.buffer.reserve(data.len());
+        self.buffer.extend_from_slice(data);
+    }
+
+    /// Append a single byte
+    pub fn put_u8(&mut self, value: u8) {
+        self.buffer.reserve(1);
+        self.buffer.push(value);
+    }
+
+    /// Append a `u64` in little-endian byte order, matching the fixed-width encoding used
+    /// elsewhere for platform-stable serialization (see [`crate::ffi::fingerprint`])
+    pub fn put_u64_le(&mut self, value: u64) {
+        self.put_slice(&value.to_le_bytes());
+    }
+
+    /// The writer's uninitialized tail, for callers that want to write directly into the
+    /// buffer (e.g. a SIMD encoder) instead of going through `put_*`
+    ///
+    /// Call [`Self::advance_mut`] afterward with the number of bytes actually initialized.
+    ///
+    /// # Safety
+    ///
+    /// The caller must initialize every byte it later claims via `advance_mut` before that
+    /// call, since reading past the buffer's length without doing so is reading
+    /// uninitialized memory.
+    pub unsafe fn chunk_mut(&mut self, additional: usize) -> &mut [MaybeUninit<u8>] {
+        self.buffer.reserve(additional);
+        let len = self.buffer.len();
+        let cap = self.buffer.capacity();
+
+        // Safety: `len..cap` is spare capacity just reserved above, so it's valid (if
+        // uninitialized) memory owned by `self.buffer` for the lifetime of this borrow.
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                self.buffer.as_mut_ptr().add(len) as *mut MaybeUninit<u8>,
+                cap - len,
+            )
+        }
+    }
+
+    /// Mark `count` bytes of the tail returned by [`Self::chunk_mut`] as written, extending
+    /// the buffer's length
+    ///
+    /// # Safety
+    ///
+    /// The caller must have initialized at least `count` bytes via the slice most recently
+    /// returned by `chunk_mut`, and `count` must not exceed `remaining_mut()`.
+    pub unsafe fn advance_mut(&mut self, count: usize) {
+        let new_len = self.buffer.len() + count;
+        debug_assert!(new_len <= self.buffer.capacity());
+
+        // Safety: the caller guarantees `count` bytes past the current length were just
+        // initialized, and we've checked the new length stays within capacity.
+        unsafe {
+            self.buffer.set_len(new_len);
+        }
+    }
+
+    /// Borrow everything written so far
+    pub fn as_slice(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// Finalize the written bytes into an owned `String`, for callers assembling plain
+    /// Rust text (e.g. a protocol command line) rather than handing off across FFI
+    ///
+    /// # Panics
+    ///
+    /// Panics if the written bytes aren't valid UTF-8 — only call this when every `put_*`
+    /// call wrote UTF-8 text.
+    pub fn into_string(self) -> String {
+        String::from_utf8(self.into_bytes()).expect("BufMutWriter::into_string: invalid UTF-8")
+    }
+
+    /// Finalize the written bytes into an owned, heap-allocated `Vec<u8>`
+    pub fn into_bytes(mut self) -> Vec<u8> {
+        std::mem::take(&mut self.buffer)
+    }
+
+    /// Finalize the written bytes into a single newly `malloc`-ed, NUL-terminated C string
+    ///
+    /// Everything written so far goes into one allocation with one copy, rather than one
+    /// `libc::malloc`/`ptr::copy_nonoverlapping` pair per field. Returns null if the
+    /// allocation fails. The caller
+    /// owns the returned pointer and must free it with `libc::free` (or the matching FFI
+    /// free function).
+    pub fn into_c_string(mut self) -> *mut c_char {
+        let bytes = std::mem::take(&mut self.buffer);
+
+        // Safety: `alloc` is freshly allocated with room for `bytes.len()` data bytes plus
+        // a trailing NUL, and is null-checked before being written to.
+        unsafe {
+            let alloc = libc::malloc(bytes.len() + 1) as *mut u8;
+            if alloc.is_null() {
+                return ptr::null_mut();
+            }
+
+            ptr::copy_nonoverlapping(bytes.as_ptr(), alloc, bytes.len());
+            *alloc.add(bytes.len()) = 0;
+            alloc as *mut c_char
+        }
+    }
+}
+
+impl Drop for BufMutWriter {
+    fn drop(&mut self) {
+        let buffer = std::mem::take(&mut self.buffer);
+        if buffer.capacity() > 0 {
+            crate::ffi_string_pool::release_pooled_buffer(buffer);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    #[test]
+    fn test_put_slice_and_put_u8() {
+        let mut writer = BufMutWriter::new();
+        writer.put_slice(b"AUTH ");
+        writer.put_u8(b'!');
+        assert_eq!(writer.as_slice(), b"AUTH !");
+    }
+
+    #[test]
+    fn test_put_u64_le() {
+        let mut writer = BufMutWriter::new();
+        writer.put_u64_le(0x0102030405060708);
+        assert_eq!(writer.as_slice(), &[8, 7, 6, 5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_remaining_mut_shrinks_as_bytes_are_written() {
+        let mut writer = BufMutWriter::with_capacity(16);
+        let before = writer.remaining_mut();
+        writer.put_slice(b"abcd");
+        assert_eq!(writer.remaining_mut(), before - 4);
+    }
+
+    #[test]
+    fn test_chunk_mut_and_advance_mut_roundtrip() {
+        let mut writer = BufMutWriter::with_capacity(8);
+
+        unsafe {
+            let chunk = writer.chunk_mut(4);
+            for (i, slot) in chunk.iter_mut().take(4).enumerate() {
+                slot.write(b'a' + i as u8);
+            }
+            writer.advance_mut(4);
+        }
+
+        assert_eq!(writer.as_slice(), b"abcd");
+    }
+
+    #[test]
+    fn test_into_string_concatenates_writes() {
+        let mut writer = BufMutWriter::new();
+        writer.put_slice(b"AUTH user=");
+        writer.put_slice(b"alice");
+        assert_eq!(writer.into_string(), "AUTH user=alice");
+    }
+
+    #[test]
+    fn test_into_c_string_null_terminates() {
+        let mut writer = BufMutWriter::new();
+        writer.put_slice(b"hello");
+
+        let ptr = writer.into_c_string();
+        assert!(!ptr.is_null());
+
+        unsafe {
+            let c_str = CStr::from_ptr(ptr);
+            assert_eq!(c_str.to_str().unwrap(), "hello");
+            libc::free(ptr as *mut libc::c_void);
+        }
+    }
+
+    #[test]
+    fn test_buffer_is_returned_to_pool_after_drop() {
+        let stats_before = crate::ffi_string_pool::string_pool_stats();
+
+        {
+            let mut writer = BufMutWriter::with_capacity(32);
+            writer.put_slice(b"scratch");
+        }
+
+        let stats_after = crate::ffi_string_pool::string_pool_stats();
+        assert_eq!(stats_after.pushes, stats_before.pushes + 1);
+    }
+}
@@ -0,0 +1,325 @@
+//! Stable content fingerprints for file results
+//!
+//! [`stable_fingerprint`] gives hosts a deterministic, endian-independent 128-bit cache
+//! key for an [`AniDBFileResult`], suitable for persisting a dedup/cache index to disk
+//! and reusing it across machines or target architectures -- unlike `Hash`, whose output
+//! is explicitly documented as unstable across Rust versions and platforms.
+
+use crate::ffi::types::{AniDBFileResult, AniDBHashResult};
+use std::ffi::{CStr, c_char};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+/// Minimal SipHash-1-3 (c=1, d=3 compression rounds) producing a full 128-bit digest,
+/// keyed with fixed all-zero keys.
+///
+/// This isn't used anywhere security-sensitive -- there's no secret key -- it's only a
+/// deterministic, well-mixed hash function for [`stable_fingerprint`], picked because its
+/// 128-bit finalization (two differently-salted squeezes of the same state) gives a far
+/// lower collision probability than truncating to a 64-bit hash.
+struct SipHash13 {
+    v0: u64,
+    v1: u64,
+    v2: u64,
+    v3: u64,
+    tail: [u8; 8],
+    tail_len: usize,
+    total_len: u64,
+}
+
+impl SipHash13 {
+    fn new() -> Self {
+        Self {
+            v0: 0x736f6d6570736575,
+            v1: 0x646f72616e646f6d ^ 0xee,
+            v2: 0x6c7967656e657261,
+            v3: 0x7465646279746573,
+            tail: [0; 8],
+            tail_len: 0,
+            total_len: 0,
+        }
+    }
+
+    #[inline]
+    fn round(&mut self) {
+        self.v0 = self.v0.wrapping_add(self.v1);
+        self.v1 = self.v1.rotate_left(13);
+        self.v1 ^= self.v0;
+        self.v0 = self.v0.rotate_left(32);
+
+        self.v2 = self.v2.wrapping_add(self.v3);
+        self.v3 = self.v3.rotate_left(16);
+        self.v3 ^= self.v2;
+
+        self.v0 = self.v0.wrapping_add(self.v3);
+        self.v3 = self.v3.rotate_left(21);
+        self.v3 ^= self.v0;
+
+        self.v2 = self.v2.wrapping_add(self.v1);
+        self.v1 = self.v1.rotate_left(17);
+        self.v1 ^= self.v2;
+        self.v2 = self.v2.rotate_left(32);
+    }
+
+    #[inline]
+    fn process_block(&mut self, m: u64) {
+        self.v3 ^= m;
+        self.round(); // c = 1 compression round
+        self.v0 ^= m;
+    }
+
+    fn write(&mut self, mut bytes: &[u8]) {
+        self.total_len += bytes.len() as u64;
+
+        if self.tail_len > 0 {
+            let take = (8 - self.tail_len).min(bytes.len());
+            self.tail[self.tail_len..self.tail_len + take].copy_from_slice(&bytes[..take]);
+            self.tail_len += take;
+            bytes = &bytes[take..];
+
+            if self.tail_len < 8 {
+                return;
+            }
+
+            let m = u64::from_le_bytes(self.tail);
+            self.process_block(m);
+            self.tail_len = 0;
+        }
+
+        while bytes.len() >= 8 {
+            let m = u64::from_le_bytes(bytes[..8].try_into().unwrap());
+            self.process_block(m);
+            bytes = &bytes[8..];
+        }
+
+        self.tail[..bytes.len()].copy_from_slice(bytes);
+        self.tail_len = bytes.len();
+    }
+
+    fn finish128(mut self) -> u128 {
+        let mut last_block = [0u8; 8];
+        last_block[..self.tail_len].copy_from_slice(&self.tail[..self.tail_len]);
+        last_block[7] = (self.total_len & 0xff) as u8;
+        let b = u64::from_le_bytes(last_block);
+
+        self.v3 ^= b;
+        self.round();
+        self.v0 ^= b;
+
+        self.v2 ^= 0xee;
+        self.round();
+        self.round();
+        self.round(); // d = 3 finalization rounds
+
+        let lo = self.v0 ^ self.v1 ^ self.v2 ^ self.v3;
+
+        self.v1 ^= 0xdd;
+        self.round();
+        self.round();
+        self.round();
+
+        let hi = self.v0 ^ self.v1 ^ self.v2 ^ self.v3;
+
+        ((hi as u128) << 64) | (lo as u128)
+    }
+}
+
+/// Hash an optional C string as a presence flag followed by its length-prefixed bytes,
+/// so a null pointer and an empty string never collide, and pointer values themselves
+/// are never part of the digest.
+///
+/// # Safety
+///
+/// `ptr`, if non-null, must point to a valid NUL-terminated C string.
+unsafe fn write_opt_c_str(hasher: &mut SipHash13, ptr: *const c_char) {
+    match unsafe { (!ptr.is_null()).then(|| CStr::from_ptr(ptr)) } {
+        Some(c_str) => {
+            let bytes = c_str.to_bytes();
+            hasher.write(&[1u8]);
+            hasher.write(&(bytes.len() as u64).to_le_bytes());
+            hasher.write(bytes);
+        }
+        None => hasher.write(&[0u8]),
+    }
+}
+
+/// Compute a deterministic, endian-independent 128-bit fingerprint of a file result
+///
+/// Every integer field is written as fixed-width little-endian regardless of host
+/// endianness, `usize` lengths are widened to `u64` before hashing, and string fields
+/// are hashed as length-prefixed byte slices rather than through their pointer, so the
+/// fingerprint stays identical across machines, processes, and target word sizes. Hosts
+/// can use this as a persistent dedup/cache key.
+///
+/// # Safety
+///
+/// `result.file_path`, `result.error_message`, and `result.hashes` (together with each
+/// entry's `hash_value`), if non-null, must point to valid data for the duration of this
+/// call: `file_path`/`error_message`/`hash_value` as NUL-terminated C strings, `hashes` as
+/// an array of `result.hash_count` initialized [`AniDBHashResult`] entries.
+pub unsafe fn stable_fingerprint(result: &AniDBFileResult) -> u128 {
+    let mut hasher = SipHash13::new();
+
+    unsafe {
+        write_opt_c_str(&mut hasher, result.file_path);
+    }
+    hasher.write(&result.file_size.to_le_bytes());
+    hasher.write(&(result.status as u32).to_le_bytes());
+    hasher.write(&(result.hash_count as u64).to_le_bytes());
+    hasher.write(&result.processing_time_ms.to_le_bytes());
+
+    if !result.hashes.is_null() && result.hash_count > 0 {
+        let hashes = unsafe { std::slice::from_raw_parts(result.hashes, result.hash_count) };
+        for hash in hashes {
+            hasher.write(&(hash.algorithm as u32).to_le_bytes());
+            unsafe {
+                write_opt_c_str(&mut hasher, hash.hash_value);
+            }
+            hasher.write(&(hash.hash_length as u64).to_le_bytes());
+        }
+    }
+
+    unsafe {
+        write_opt_c_str(&mut hasher, result.error_message);
+    }
+
+    hasher.finish128()
+}
+
+/// A 128-bit fingerprint split into two `u64` lanes for the C ABI, since `u128` isn't a
+/// portable `extern "C"` return type
+#[repr(C)]
+pub struct AniDBFingerprint {
+    pub hi: u64,
+    pub lo: u64,
+}
+
+/// Compute a stable content fingerprint for a file result (see [`stable_fingerprint`])
+///
+/// Returns an all-zero fingerprint if `result` is null or a panic is caught while reading
+/// it.
+#[unsafe(no_mangle)]
+pub extern "C" fn anidb_file_result_fingerprint(
+    result: *const AniDBFileResult,
+) -> AniDBFingerprint {
+    let fingerprint = catch_unwind(AssertUnwindSafe(|| {
+        if result.is_null() {
+            return 0u128;
+        }
+        unsafe { stable_fingerprint(&*result) }
+    }))
+    .unwrap_or(0);
+
+    AniDBFingerprint {
+        hi: (fingerprint >> 64) as u64,
+        lo: fingerprint as u64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ffi::types::{AniDBHashAlgorithm, AniDBStatus};
+    use std::ffi::CString;
+    use std::ptr;
+
+    fn sample_result(file_path: &CString) -> AniDBFileResult {
+        AniDBFileResult {
+            file_path: file_path.as_ptr() as *mut c_char,
+            file_size: 123_456,
+            status: AniDBStatus::Completed,
+            hashes: ptr::null_mut(),
+            hash_count: 0,
+            processing_time_ms: 42,
+            error_message: ptr::null_mut(),
+        }
+    }
+
+    #[test]
+    fn test_fingerprint_is_deterministic() {
+        let path = CString::new("/anime/episode.mkv").unwrap();
+        let result = sample_result(&path);
+
+        let a = unsafe { stable_fingerprint(&result) };
+        let b = unsafe { stable_fingerprint(&result) };
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_fingerprint_changes_with_file_size() {
+        let path = CString::new("/anime/episode.mkv").unwrap();
+        let mut result = sample_result(&path);
+
+        let a = unsafe { stable_fingerprint(&result) };
+        result.file_size += 1;
+        let b = unsafe { stable_fingerprint(&result) };
+
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_fingerprint_distinguishes_null_and_empty_path() {
+        let empty_path = CString::new("").unwrap();
+        let mut with_empty = sample_result(&empty_path);
+        let mut with_null = sample_result(&empty_path);
+        with_null.file_path = ptr::null_mut();
+        with_empty.file_path = empty_path.as_ptr() as *mut c_char;
+
+        let a = unsafe { stable_fingerprint(&with_empty) };
+        let b = unsafe { stable_fingerprint(&with_null) };
+
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_fingerprint_hashes_values_not_pointers() {
+        let path_a = CString::new("/anime/episode.mkv").unwrap();
+        let path_b = CString::new("/anime/episode.mkv").unwrap();
+        let result_a = sample_result(&path_a);
+        let result_b = sample_result(&path_b);
+
+        // Two distinct `CString` allocations with identical contents must fingerprint
+        // the same, proving the pointer value itself never enters the hash.
+        let a = unsafe { stable_fingerprint(&result_a) };
+        let b = unsafe { stable_fingerprint(&result_b) };
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_fingerprint_includes_hash_entries() {
+        let path = CString::new("/anime/episode.mkv").unwrap();
+        let mut result = sample_result(&path);
+
+        let hash_hex = CString::new("098f6bcd4621d373cade4e832627b4f6").unwrap();
+        let mut hash_entry = AniDBHashResult {
+            algorithm: AniDBHashAlgorithm::MD5,
+            hash_value: hash_hex.as_ptr() as *mut c_char,
+            hash_length: hash_hex.as_bytes().len(),
+        };
+
+        let without_hashes = unsafe { stable_fingerprint(&result) };
+
+        result.hashes = &mut hash_entry;
+        result.hash_count = 1;
+        let with_hashes = unsafe { stable_fingerprint(&result) };
+
+        assert_ne!(without_hashes, with_hashes);
+    }
+
+    #[test]
+    fn test_c_abi_fingerprint_matches_rust_api() {
+        let path = CString::new("/anime/episode.mkv").unwrap();
+        let result = sample_result(&path);
+
+        let expected = unsafe { stable_fingerprint(&result) };
+        let via_ffi = anidb_file_result_fingerprint(&result as *const AniDBFileResult);
+
+        assert_eq!(((via_ffi.hi as u128) << 64) | via_ffi.lo as u128, expected);
+    }
+
+    #[test]
+    fn test_c_abi_fingerprint_null_is_zero() {
+        let via_ffi = anidb_file_result_fingerprint(ptr::null());
+        assert_eq!(via_ffi.hi, 0);
+        assert_eq!(via_ffi.lo, 0);
+    }
+}
@@ -51,6 +51,7 @@ pub(crate) struct ClientState {
     pub file_processor: Arc<FileProcessor>,
     pub runtime: Arc<Runtime>,
     pub last_error: Option<String>,
+    pub last_error_info: Option<crate::ffi::helpers::LastErrorInfo>,
     #[allow(dead_code)]
     pub reference_count: AtomicUsize,
 
@@ -200,6 +201,7 @@ pub(crate) fn create_client_with_config(
         file_processor,
         runtime,
         last_error: None,
+        last_error_info: None,
         reference_count: AtomicUsize::new(1),
         callbacks: Arc::new(Mutex::new(HashMap::new())),
         next_callback_id: Arc::new(AtomicU64::new(1)),
@@ -4,7 +4,7 @@
 //! panic catching, validation, string conversion, and callback invocation.
 
 use crate::ffi::handles::{CallbackRegistration, NEXT_HANDLE_ID};
-use crate::ffi::types::{AniDBCallbackType, AniDBHashAlgorithm, AniDBResult};
+use crate::ffi::types::{AniDBCallbackType, AniDBErrorCategory, AniDBHashAlgorithm, AniDBResult};
 use crate::ffi_memory::ffi_allocate_string;
 use crate::{Error, HashAlgorithm};
 use std::collections::HashMap;
@@ -74,6 +74,61 @@ pub(crate) fn error_to_result(error: &Error) -> AniDBResult {
     }
 }
 
+/// Structured detail behind [`crate::ffi::handles::ClientState::last_error`], computed
+/// once when the error occurs so `anidb_get_last_error_info` doesn't need to re-derive
+/// category/retryability from a plain, already-lossy string
+pub(crate) struct LastErrorInfo {
+    pub error_code: AniDBResult,
+    pub category: AniDBErrorCategory,
+    pub retryable: bool,
+    pub message: String,
+    pub file_path: Option<String>,
+    pub os_errno: i32,
+}
+
+/// Convert a Rust error into the structured detail surfaced by `anidb_get_last_error_info`
+pub(crate) fn error_to_info(error: &Error) -> LastErrorInfo {
+    use crate::error::{ProtocolError, ValidationError};
+
+    let (category, retryable, file_path, os_errno) = match error {
+        Error::Io(io_err) => (
+            AniDBErrorCategory::Io,
+            false,
+            io_err.path.as_ref().map(|p| p.display().to_string()),
+            io_err
+                .source
+                .as_ref()
+                .and_then(std::io::Error::raw_os_error)
+                .unwrap_or(0),
+        ),
+        Error::Protocol(proto_err) => (
+            AniDBErrorCategory::Protocol,
+            proto_err.is_transient(),
+            None,
+            0,
+        ),
+        Error::Validation(val_err) => (
+            AniDBErrorCategory::InvalidInput,
+            false,
+            match val_err {
+                ValidationError::PathTooLong { path, .. } => Some(path.display().to_string()),
+                _ => None,
+            },
+            0,
+        ),
+        Error::Internal(int_err) => (AniDBErrorCategory::Internal, int_err.is_recoverable(), None, 0),
+    };
+
+    LastErrorInfo {
+        error_code: error_to_result(error),
+        category,
+        retryable,
+        message: error.to_string(),
+        file_path,
+        os_errno,
+    }
+}
+
 /// Convert C string to Rust string with safety checks
 pub(crate) fn c_str_to_string(s: *const c_char) -> Result<String, AniDBResult> {
     if !validate_c_str(s) {
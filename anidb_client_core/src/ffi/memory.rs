@@ -4,7 +4,9 @@
 //! deallocation, memory statistics, and garbage collection.
 
 use crate::ffi::helpers::validate_mut_ptr;
-use crate::ffi::types::{AniDBBatchResult, AniDBFileResult, AniDBHashResult, AniDBResult};
+use crate::ffi::types::{
+    AniDBBatchResult, AniDBErrorInfo, AniDBFileResult, AniDBHashResult, AniDBResult,
+};
 use crate::ffi_catch_panic;
 use crate::ffi_memory::{
     MemoryPressure, check_memory_pressure, ffi_free_string, ffi_release_buffer, get_memory_stats,
@@ -73,6 +75,29 @@ pub extern "C" fn anidb_free_file_result(result: *mut AniDBFileResult) {
     }));
 }
 
+/// Free an error info structure returned by `anidb_get_last_error_info`
+#[unsafe(no_mangle)]
+pub extern "C" fn anidb_free_error_info(info: *mut AniDBErrorInfo) {
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        if !validate_mut_ptr(info) {
+            return;
+        }
+
+        unsafe {
+            let info_box = Box::from_raw(info);
+
+            if validate_mut_ptr(info_box.message) {
+                ffi_free_string(info_box.message);
+            }
+            if validate_mut_ptr(info_box.file_path) {
+                ffi_free_string(info_box.file_path);
+            }
+
+            // Box automatically deallocates
+        }
+    }));
+}
+
 /// Free a batch result structure
 #[unsafe(no_mangle)]
 pub extern "C" fn anidb_free_batch_result(result: *mut AniDBBatchResult) {
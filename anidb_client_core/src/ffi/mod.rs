@@ -21,6 +21,7 @@
 // Module declarations
 pub mod callbacks;
 pub mod events;
+pub mod fingerprint;
 pub mod handles;
 pub mod helpers;
 pub mod memory;
@@ -31,6 +32,7 @@ pub mod types;
 // Re-export all public FFI functions and types
 pub use callbacks::*;
 pub use events::*;
+pub use fingerprint::*;
 pub use handles::*;
 pub use memory::*;
 pub use operations::*;
@@ -10,8 +10,8 @@ use crate::ffi::helpers::*;
 use crate::ffi::types::*;
 use crate::ffi_catch_panic;
 use crate::ffi_memory::{
-    AllocationType, MemoryPressure, check_memory_pressure, ffi_allocate_buffer, ffi_free_string,
-    get_memory_stats,
+    AllocationType, MemoryPressure, check_memory_pressure, ffi_allocate_buffer, ffi_allocate_string,
+    ffi_free_string, get_memory_stats,
 };
 use crate::progress::{ProgressProvider, ProgressUpdate};
 use std::ffi::{CString, c_char, c_void};
@@ -341,11 +341,13 @@ pub extern "C" fn anidb_process_file(
                 });
 
                 client.last_error = None;
+                client.last_error_info = None;
                 AniDBResult::Success
             }
             Err(e) => {
                 let error_msg = e.to_string();
                 client.last_error = Some(error_msg.clone());
+                client.last_error_info = Some(error_to_info(&e));
                 let error_result = error_to_result(&e);
 
                 // Call error callbacks
@@ -485,3 +487,67 @@ pub extern "C" fn anidb_client_get_last_error(
         AniDBResult::Success
     })
 }
+
+/// Get structured, machine-readable detail for the last error on a client
+///
+/// On success, `*info` is set to a heap-allocated [`AniDBErrorInfo`] that the caller must
+/// release with `anidb_free_error_info`. Returns `AniDBResult::ErrorUnknown` if the client
+/// has no recorded error (nothing to report, as opposed to a failure of this call).
+#[unsafe(no_mangle)]
+pub extern "C" fn anidb_get_last_error_info(
+    handle: *mut c_void,
+    info: *mut *mut AniDBErrorInfo,
+) -> AniDBResult {
+    ffi_catch_panic!({
+        if !validate_mut_ptr(handle) || !validate_mut_ptr(info) {
+            return AniDBResult::ErrorInvalidParameter;
+        }
+
+        let handle_id = handle as usize;
+
+        if handle_id == 0 || handle_id > usize::MAX / 2 {
+            return AniDBResult::ErrorInvalidHandle;
+        }
+
+        let clients = match CLIENTS.read() {
+            Ok(c) => c,
+            Err(_) => return AniDBResult::ErrorBusy,
+        };
+
+        let client_arc = match clients.get(&handle_id) {
+            Some(c) => c.clone(),
+            None => return AniDBResult::ErrorInvalidHandle,
+        };
+
+        // Release read lock before acquiring client lock
+        drop(clients);
+
+        let client = match client_arc.lock() {
+            Ok(c) => c,
+            Err(_) => return AniDBResult::ErrorBusy,
+        };
+
+        let Some(last_error) = client.last_error_info.as_ref() else {
+            return AniDBResult::ErrorUnknown;
+        };
+
+        let error_info = Box::new(AniDBErrorInfo {
+            error_code: last_error.error_code,
+            category: last_error.category,
+            retryable: last_error.retryable,
+            message: ffi_allocate_string(&last_error.message),
+            file_path: last_error
+                .file_path
+                .as_deref()
+                .map(ffi_allocate_string)
+                .unwrap_or(ptr::null_mut()),
+            os_errno: last_error.os_errno,
+        });
+
+        unsafe {
+            *info = Box::into_raw(error_info);
+        }
+
+        AniDBResult::Success
+    })
+}
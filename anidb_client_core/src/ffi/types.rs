@@ -31,6 +31,33 @@ pub enum AniDBResult {
     ErrorUnknown = 99,
 }
 
+/// Coarse category for a structured [`AniDBErrorInfo`], letting callers branch on the
+/// general shape of a failure without string-matching `message`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AniDBErrorCategory {
+    Io = 0,
+    Protocol = 1,
+    InvalidInput = 2,
+    Internal = 3,
+}
+
+/// Structured, machine-readable detail behind an [`AniDBResult`]
+///
+/// Returned by `anidb_get_last_error_info` and owned by the caller until passed to
+/// `anidb_free_error_info`. `file_path` is null when the error wasn't associated with a
+/// specific file; `os_errno` is 0 when the error didn't originate from a `std::io::Error`
+/// carrying a raw OS error code.
+#[repr(C)]
+pub struct AniDBErrorInfo {
+    pub error_code: AniDBResult,
+    pub category: AniDBErrorCategory,
+    pub retryable: bool,
+    pub message: *mut c_char,
+    pub file_path: *mut c_char,
+    pub os_errno: i32,
+}
+
 /// Hash algorithm identifiers matching the C header
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
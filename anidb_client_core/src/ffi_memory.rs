@@ -213,9 +213,14 @@ pub struct LeakInfo {
 }
 
 /// Allocate a string for FFI with tracking
+///
+/// Builds the string in a buffer from the lock-free string pool (see
+/// [`crate::ffi_string_pool`]) when one of sufficient capacity is available. `CString`
+/// keeps its bytes in an exact-length allocation, so a pooled buffer with spare capacity
+/// is still shrunk through the global allocator.
 pub fn ffi_allocate_string(s: &str) -> *mut c_char {
-    match CString::new(s) {
-        Ok(c_str) => {
+    match crate::ffi_string_pool::pooled_cstring(s) {
+        Some(c_str) => {
             let ptr = c_str.into_raw();
             ALLOCATION_TRACKER.track_allocation(
                 ptr as *const u8,
@@ -224,12 +229,15 @@ pub fn ffi_allocate_string(s: &str) -> *mut c_char {
             );
             ptr
         }
-        Err(_) => ptr::null_mut(),
+        None => ptr::null_mut(),
     }
 }
 
 /// Free a string allocated for FFI
 ///
+/// Returns the buffer to the lock-free string pool (see [`crate::ffi_string_pool`])
+/// instead of deallocating it, so a subsequent `ffi_allocate_string` call can reuse it.
+///
 /// # Safety
 ///
 /// The pointer must have been allocated by `ffi_allocate_string` and not
@@ -241,7 +249,8 @@ pub unsafe fn ffi_free_string(ptr: *mut c_char) {
 
     ALLOCATION_TRACKER.track_deallocation(ptr as *const u8);
     unsafe {
-        let _ = CString::from_raw(ptr);
+        let c_string = CString::from_raw(ptr);
+        crate::ffi_string_pool::release_pooled_cstring(c_string);
     }
 }
 
@@ -39,18 +39,11 @@ pub fn optimized_string_alloc(s: &str) -> *mut c_char {
             if buf.capacity() > len {
                 buf.clear();
                 buf.extend_from_slice(s.as_bytes());
-                buf.push(0); // null terminator
-
-                // Allocate permanent copy
-                let ptr = unsafe {
-                    let alloc = libc::malloc(buf.len()) as *mut u8;
-                    if !alloc.is_null() {
-                        ptr::copy_nonoverlapping(buf.as_ptr(), alloc, buf.len());
-                    }
-                    alloc as *mut c_char
-                };
-
-                return ptr;
+
+                // Allocate and copy directly from the thread-local buffer. Routing this
+                // through BufMutWriter would cost a second copy into a pooled buffer for
+                // no benefit, since `buf` already holds the exact bytes to hand to libc.
+                return alloc_c_string(&buf);
             }
 
             // Fall back to standard allocation
@@ -61,6 +54,25 @@ pub fn optimized_string_alloc(s: &str) -> *mut c_char {
     }
 }
 
+/// Allocate a single `malloc`-ed, NUL-terminated C string copied from `bytes`
+///
+/// Returns a null pointer if allocation fails, matching `ffi_allocate_string`'s
+/// null-on-failure convention.
+fn alloc_c_string(bytes: &[u8]) -> *mut c_char {
+    // Safety: `alloc` is freshly allocated with room for `bytes.len()` data bytes plus a
+    // trailing NUL, and is null-checked before being written to.
+    unsafe {
+        let alloc = libc::malloc(bytes.len() + 1) as *mut u8;
+        if alloc.is_null() {
+            return ptr::null_mut();
+        }
+
+        ptr::copy_nonoverlapping(bytes.as_ptr(), alloc, bytes.len());
+        *alloc.add(bytes.len()) = 0;
+        alloc as *mut c_char
+    }
+}
+
 /// Optimized C string parsing with minimal overhead
 ///
 /// Avoids UTF-8 validation for trusted paths
@@ -92,6 +104,15 @@ pub unsafe fn fast_c_str_to_path(s: *const c_char) -> Result<&'static str, AniDB
     }
 }
 
+/// Output encoding requested from [`create_hash_result_zero_copy_with_encoding`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashOutputEncoding {
+    /// Copy the hash's bytes into the buffer verbatim
+    Raw,
+    /// Base64-encode the hash's bytes via [`simd_base64_encode_hash`]
+    Base64,
+}
+
 /// Zero-copy hash result creation
 ///
 /// Creates hash results without intermediate allocations
@@ -100,24 +121,57 @@ pub fn create_hash_result_zero_copy(
     algorithm: AniDBHashAlgorithm,
     hash: &str,
     buffer: &mut [u8],
+) -> Result<AniDBHashResult, AniDBResult> {
+    create_hash_result_zero_copy_with_encoding(algorithm, hash, buffer, HashOutputEncoding::Raw)
+}
+
+/// Zero-copy hash result creation with a choice of output encoding
+///
+/// Like [`create_hash_result_zero_copy`], but lets callers request a base64-encoded
+/// digest (written via [`simd_base64_encode_hash`]) instead of the raw hash text, still
+/// without any intermediate `String` allocation.
+#[inline]
+pub fn create_hash_result_zero_copy_with_encoding(
+    algorithm: AniDBHashAlgorithm,
+    hash: &str,
+    buffer: &mut [u8],
+    encoding: HashOutputEncoding,
 ) -> Result<AniDBHashResult, AniDBResult> {
     let hash_bytes = hash.as_bytes();
-    let required_size = hash_bytes.len() + 1;
 
-    if buffer.len() < required_size {
-        return Err(AniDBResult::ErrorInvalidParameter);
-    }
+    let hash_length = match encoding {
+        HashOutputEncoding::Raw => {
+            let required_size = hash_bytes.len() + 1;
+            if buffer.len() < required_size {
+                return Err(AniDBResult::ErrorInvalidParameter);
+            }
 
-    // Copy hash value directly into provided buffer
-    unsafe {
-        ptr::copy_nonoverlapping(hash_bytes.as_ptr(), buffer.as_mut_ptr(), hash_bytes.len());
-        buffer[hash_bytes.len()] = 0; // null terminator
-    }
+            // Copy hash value directly into provided buffer
+            unsafe {
+                ptr::copy_nonoverlapping(hash_bytes.as_ptr(), buffer.as_mut_ptr(), hash_bytes.len());
+                buffer[hash_bytes.len()] = 0; // null terminator
+            }
+
+            hash_bytes.len()
+        }
+        HashOutputEncoding::Base64 => {
+            let encoded_len = (hash_bytes.len() + 2) / 3 * 4;
+            let required_size = encoded_len + 1;
+            if buffer.len() < required_size {
+                return Err(AniDBResult::ErrorInvalidParameter);
+            }
+
+            let written = simd_base64_encode_hash(hash_bytes, &mut buffer[..encoded_len]);
+            buffer[written] = 0; // null terminator
+
+            written
+        }
+    };
 
     Ok(AniDBHashResult {
         algorithm,
         hash_value: buffer.as_mut_ptr() as *mut c_char,
-        hash_length: hash_bytes.len(),
+        hash_length,
     })
 }
 
@@ -380,6 +434,160 @@ pub unsafe fn simd_copy_hash(src: &[u8], dst: &mut [u8]) {
     }
 }
 
+/// Padding byte for base64 output whose input length isn't a multiple of 3
+const BASE64_PAD: u8 = b'=';
+
+/// Map a six-bit value (0-63) to its base64 ASCII character with pure arithmetic instead
+/// of a 64-entry lookup table: start from the 'A' range and fold in each subsequent
+/// range's offset as the value crosses into it.
+#[inline]
+fn base64_translate_scalar(v: u8) -> u8 {
+    let mut c = v.wrapping_add(65); // 'A'..'Z' for v in 0..=25
+    if v > 25 {
+        c = c.wrapping_add(6); // 'a'..'z' for v in 26..=51
+    }
+    if v > 51 {
+        c = c.wrapping_sub(75); // '0'..'9' for v in 52..=61
+    }
+    if v == 62 {
+        c = c.wrapping_sub(15); // '+'
+    }
+    if v == 63 {
+        c = c.wrapping_sub(12); // '/'
+    }
+    c
+}
+
+/// Scalar base64 encoder, used as the universal fallback and to finish off whatever tail
+/// doesn't fill a full SIMD batch
+fn base64_encode_scalar(src: &[u8], dst: &mut [u8]) -> usize {
+    let mut out = 0;
+    let mut chunks = src.chunks_exact(3);
+
+    for chunk in &mut chunks {
+        let v = ((chunk[0] as u32) << 16) | ((chunk[1] as u32) << 8) | chunk[2] as u32;
+        dst[out] = base64_translate_scalar(((v >> 18) & 0x3F) as u8);
+        dst[out + 1] = base64_translate_scalar(((v >> 12) & 0x3F) as u8);
+        dst[out + 2] = base64_translate_scalar(((v >> 6) & 0x3F) as u8);
+        dst[out + 3] = base64_translate_scalar((v & 0x3F) as u8);
+        out += 4;
+    }
+
+    match chunks.remainder() {
+        [b0] => {
+            let v = (*b0 as u32) << 16;
+            dst[out] = base64_translate_scalar(((v >> 18) & 0x3F) as u8);
+            dst[out + 1] = base64_translate_scalar(((v >> 12) & 0x3F) as u8);
+            dst[out + 2] = BASE64_PAD;
+            dst[out + 3] = BASE64_PAD;
+            out += 4;
+        }
+        [b0, b1] => {
+            let v = ((*b0 as u32) << 16) | ((*b1 as u32) << 8);
+            dst[out] = base64_translate_scalar(((v >> 18) & 0x3F) as u8);
+            dst[out + 1] = base64_translate_scalar(((v >> 12) & 0x3F) as u8);
+            dst[out + 2] = base64_translate_scalar(((v >> 6) & 0x3F) as u8);
+            dst[out + 3] = BASE64_PAD;
+            out += 4;
+        }
+        _ => {}
+    }
+
+    out
+}
+
+/// Vectorized base64 char-mapping: applies [`base64_translate_scalar`]'s arithmetic to 32
+/// six-bit indices at once via greater-than comparisons and masked adds (AVX2's stand-in
+/// for a branch/blend), instead of a scalar loop or a table gather.
+///
+/// # Safety
+///
+/// The caller must ensure the CPU supports AVX2.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn base64_translate_avx2(indices: [u8; 32]) -> std::arch::x86_64::__m256i {
+    use std::arch::x86_64::*;
+
+    unsafe {
+        let v = _mm256_loadu_si256(indices.as_ptr() as *const __m256i);
+
+        let mut result = _mm256_add_epi8(v, _mm256_set1_epi8(65));
+
+        let gt25 = _mm256_cmpgt_epi8(v, _mm256_set1_epi8(25));
+        result = _mm256_add_epi8(result, _mm256_and_si256(gt25, _mm256_set1_epi8(6)));
+
+        let gt51 = _mm256_cmpgt_epi8(v, _mm256_set1_epi8(51));
+        result = _mm256_add_epi8(result, _mm256_and_si256(gt51, _mm256_set1_epi8(-75i8)));
+
+        let eq62 = _mm256_cmpeq_epi8(v, _mm256_set1_epi8(62));
+        result = _mm256_add_epi8(result, _mm256_and_si256(eq62, _mm256_set1_epi8(-15i8)));
+
+        let eq63 = _mm256_cmpeq_epi8(v, _mm256_set1_epi8(63));
+        result = _mm256_add_epi8(result, _mm256_and_si256(eq63, _mm256_set1_epi8(-12i8)));
+
+        result
+    }
+}
+
+/// AVX2-accelerated base64 encoder
+///
+/// Processes 24 source bytes (8 complete 3-byte groups) per iteration: the four 6-bit
+/// fields of each group are extracted with scalar shifts/masks into a 32-lane index
+/// buffer, then [`base64_translate_avx2`] maps all 32 lanes to ASCII in one pass. Any
+/// remaining bytes that don't fill a full 24-byte batch fall back to
+/// [`base64_encode_scalar`].
+///
+/// # Safety
+///
+/// The caller must ensure the CPU supports AVX2, and that `dst` has room for the full
+/// base64 encoding of `src`.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn simd_base64_encode_hash_avx2(src: &[u8], dst: &mut [u8]) -> usize {
+    use std::arch::x86_64::*;
+
+    let mut pos = 0;
+    let mut out = 0;
+
+    while pos + 24 <= src.len() {
+        let mut indices = [0u8; 32];
+        for (group, bytes) in src[pos..pos + 24].chunks_exact(3).enumerate() {
+            let v = ((bytes[0] as u32) << 16) | ((bytes[1] as u32) << 8) | bytes[2] as u32;
+            indices[group * 4] = ((v >> 18) & 0x3F) as u8;
+            indices[group * 4 + 1] = ((v >> 12) & 0x3F) as u8;
+            indices[group * 4 + 2] = ((v >> 6) & 0x3F) as u8;
+            indices[group * 4 + 3] = (v & 0x3F) as u8;
+        }
+
+        unsafe {
+            let ascii = base64_translate_avx2(indices);
+            _mm256_storeu_si256(dst.as_mut_ptr().add(out) as *mut __m256i, ascii);
+        }
+
+        pos += 24;
+        out += 32;
+    }
+
+    out + base64_encode_scalar(&src[pos..], &mut dst[out..])
+}
+
+/// SIMD-accelerated base64 encoding for hash digests, complementing [`simd_copy_hash`]
+///
+/// Writes the base64 encoding (with `=` padding) of `src` into `dst` and returns the
+/// number of bytes written; `dst` must hold at least `(src.len() + 2) / 3 * 4` bytes.
+/// Dispatches to the AVX2 encoder when the CPU supports it (checked at runtime, same as
+/// [`simd_copy_hash`]'s contract), otherwise uses the scalar fallback.
+pub fn simd_base64_encode_hash(src: &[u8], dst: &mut [u8]) -> usize {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            return unsafe { simd_base64_encode_hash_avx2(src, dst) };
+        }
+    }
+
+    base64_encode_scalar(src, dst)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -443,4 +651,59 @@ mod tests {
         let buf2 = pool.allocate(60).unwrap();
         assert_eq!(buf2.capacity(), 64);
     }
+
+    #[test]
+    fn test_base64_encode_scalar_known_vectors() {
+        let cases: &[(&[u8], &str)] = &[
+            (b"", ""),
+            (b"f", "Zg=="),
+            (b"fo", "Zm8="),
+            (b"foo", "Zm9v"),
+            (b"foob", "Zm9vYg=="),
+            (b"fooba", "Zm9vYmE="),
+            (b"foobar", "Zm9vYmFy"),
+        ];
+
+        for (input, expected) in cases {
+            let mut dst = vec![0u8; ((input.len() + 2) / 3) * 4];
+            let written = base64_encode_scalar(input, &mut dst);
+            assert_eq!(written, dst.len());
+            assert_eq!(std::str::from_utf8(&dst).unwrap(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_simd_base64_encode_hash_matches_scalar() {
+        // Long enough to exercise the AVX2 batch loop (on CPUs that support it) as well
+        // as its scalar tail.
+        let input: Vec<u8> = (0..=255u16).map(|b| b as u8).collect();
+        let encoded_len = ((input.len() + 2) / 3) * 4;
+
+        let mut scalar_out = vec![0u8; encoded_len];
+        base64_encode_scalar(&input, &mut scalar_out);
+
+        let mut simd_out = vec![0u8; encoded_len];
+        let written = simd_base64_encode_hash(&input, &mut simd_out);
+
+        assert_eq!(written, encoded_len);
+        assert_eq!(simd_out, scalar_out);
+    }
+
+    #[test]
+    fn test_create_hash_result_zero_copy_base64_encoding() {
+        let mut buffer = vec![0u8; 64];
+        let result = create_hash_result_zero_copy_with_encoding(
+            AniDBHashAlgorithm::MD5,
+            "foobar",
+            &mut buffer,
+            HashOutputEncoding::Base64,
+        )
+        .unwrap();
+
+        assert_eq!(result.hash_length, 8);
+        unsafe {
+            let c_str = CStr::from_ptr(result.hash_value);
+            assert_eq!(c_str.to_str().unwrap(), "Zm9vYmFy");
+        }
+    }
 }
@@ -0,0 +1,333 @@
+//! Lock-free free list for FFI string buffers
+//!
+//! `ffi_allocate_string`/`ffi_free_string` allocate and deallocate a `CString` on every
+//! call, which is wasteful under high FFI call volume (e.g. batch hashing reporting
+//! per-file error strings). This module pools freed string buffers in per-size-class
+//! lock-free Treiber stacks so a later allocation of similar size can reuse existing
+//! storage instead of going through the global allocator again.
+
+use std::ffi::CString;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicPtr, AtomicU64, AtomicUsize, Ordering};
+
+/// Don't let the pool grow without bound: buffers above this size just go back to the
+/// allocator on free instead of being pushed, so one giant string can't pin a huge chunk
+/// of memory in the pool indefinitely.
+const MAX_POOLED_CAPACITY: usize = 64 * 1024;
+
+/// Capacity floors for the size-classed pools. A buffer pushed back lands in the largest
+/// class whose floor is `<= capacity`; a buffer below every floor lands in the smallest
+/// class, so that class can hold buffers under its floor, and `pop` drops any buffer too
+/// small for the request. A fresh allocation on a pool miss is rounded up to the smallest
+/// floor `>= ` the requested size.
+const SIZE_CLASSES: [usize; 3] = [64, 256, 1024];
+
+/// Smallest size-class index whose floor is `>= min_capacity`, or the largest class if the
+/// request exceeds every floor (that class is still tried first, but `pop` falls back to a
+/// fresh allocation if its buffers turn out too small).
+fn class_for_request(min_capacity: usize) -> usize {
+    SIZE_CLASSES
+        .iter()
+        .position(|&floor| floor >= min_capacity)
+        .unwrap_or(SIZE_CLASSES.len() - 1)
+}
+
+/// Largest size-class index whose floor is `<= capacity`, or `0` if `capacity` is below
+/// every floor.
+fn class_for_capacity(capacity: usize) -> usize {
+    SIZE_CLASSES
+        .iter()
+        .rposition(|&floor| floor <= capacity)
+        .unwrap_or(0)
+}
+
+/// A single buffer sitting on a free list
+struct PoolNode {
+    next: AtomicPtr<PoolNode>,
+    buffer: Vec<u8>,
+}
+
+/// Lock-free, Treiber-stack-backed free list of string buffers for one size class
+///
+/// Popping a node unlinks it with a single CAS, same as a textbook Treiber stack, but a
+/// node removed because it was too small for the request can't be deallocated right away:
+/// another thread may have already loaded the old `head` and be about to dereference its
+/// `next` pointer, and if the allocator handed that freed address to a fresh `push` in the
+/// meantime, the stale `next` would point into a live, unrelated node instead of the free
+/// list (the classic Treiber-stack ABA hazard). Rather than a tagged-pointer generation
+/// counter (which needs a double-word CAS the stable std atomics don't offer), removed
+/// nodes are deferred onto `retired` and only actually dropped once `in_flight_pops` drops
+/// back to zero, i.e. once no `pop` call anywhere could still be holding a stale
+/// reference into memory a retirement would free.
+struct ClassPool {
+    head: AtomicPtr<PoolNode>,
+    pushes: AtomicU64,
+    pops: AtomicU64,
+    in_flight_pops: AtomicUsize,
+    retired: Mutex<Vec<Box<PoolNode>>>,
+}
+
+impl ClassPool {
+    const fn new() -> Self {
+        Self {
+            head: AtomicPtr::new(std::ptr::null_mut()),
+            pushes: AtomicU64::new(0),
+            pops: AtomicU64::new(0),
+            in_flight_pops: AtomicUsize::new(0),
+            retired: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Push a buffer back onto the free list for reuse
+    fn push(&self, buffer: Vec<u8>) {
+        let node = Box::into_raw(Box::new(PoolNode {
+            next: AtomicPtr::new(std::ptr::null_mut()),
+            buffer,
+        }));
+
+        loop {
+            let head = self.head.load(Ordering::Acquire);
+            // Safety: `node` was just created above and isn't visible to other threads yet.
+            unsafe { (*node).next.store(head, Ordering::Relaxed) };
+
+            if self
+                .head
+                .compare_exchange_weak(head, node, Ordering::Release, Ordering::Relaxed)
+                .is_ok()
+            {
+                self.pushes.fetch_add(1, Ordering::Relaxed);
+                return;
+            }
+        }
+    }
+
+    /// Pop a buffer with at least `min_capacity` bytes, if one is on the free list
+    fn pop(&self, min_capacity: usize) -> Option<Vec<u8>> {
+        self.in_flight_pops.fetch_add(1, Ordering::SeqCst);
+        let result = self.pop_inner(min_capacity);
+        // If no `pop` is in flight, nothing else can hold a stale reference into memory a
+        // deferred retirement would free, so it's safe to drop them now. Another `pop` may
+        // have started (and loaded `head`) since our decrement, so re-check while holding
+        // the `retired` lock, which every retirement also takes.
+        if self.in_flight_pops.fetch_sub(1, Ordering::SeqCst) == 1 {
+            let mut retired = self.retired.lock().unwrap();
+            if self.in_flight_pops.load(Ordering::SeqCst) == 0 {
+                retired.clear();
+            }
+        }
+        result
+    }
+
+    fn pop_inner(&self, min_capacity: usize) -> Option<Vec<u8>> {
+        loop {
+            let head = self.head.load(Ordering::Acquire);
+            if head.is_null() {
+                return None;
+            }
+
+            // Safety: `head` is non-null and was pushed by `push`, which only ever hands
+            // out nodes it allocated via `Box::into_raw`; it stays valid (it's only ever
+            // deferred-dropped via `retired`, never freed while reachable) until popped.
+            let next = unsafe { (*head).next.load(Ordering::Relaxed) };
+
+            if self
+                .head
+                .compare_exchange_weak(head, next, Ordering::Acquire, Ordering::Relaxed)
+                .is_ok()
+            {
+                self.pops.fetch_add(1, Ordering::Relaxed);
+                // Safety: we just unlinked `head`, so we have exclusive ownership of it.
+                let mut node = unsafe { Box::from_raw(head) };
+
+                let result = if node.buffer.capacity() >= min_capacity {
+                    let mut buffer = std::mem::take(&mut node.buffer);
+                    buffer.clear();
+                    Some(buffer)
+                } else {
+                    None
+                };
+
+                // Defer the actual deallocation; see the `retired` doc comment above.
+                self.retired.lock().unwrap().push(node);
+
+                if result.is_some() {
+                    return result;
+                }
+                continue;
+            }
+        }
+    }
+}
+
+/// Global string buffer pool, bucketed by [`SIZE_CLASSES`] and shared by every
+/// `ffi_allocate_string`/`ffi_free_string` call
+struct StringPool {
+    classes: [ClassPool; SIZE_CLASSES.len()],
+}
+
+impl StringPool {
+    const fn new() -> Self {
+        Self {
+            classes: [ClassPool::new(), ClassPool::new(), ClassPool::new()],
+        }
+    }
+
+    fn push(&self, buffer: Vec<u8>) {
+        if buffer.capacity() > MAX_POOLED_CAPACITY {
+            return;
+        }
+        self.classes[class_for_capacity(buffer.capacity())].push(buffer);
+    }
+
+    fn pop(&self, min_capacity: usize) -> Option<Vec<u8>> {
+        self.classes[class_for_request(min_capacity)].pop(min_capacity)
+    }
+
+    fn pushes(&self) -> u64 {
+        self.classes.iter().map(|c| c.pushes.load(Ordering::Relaxed)).sum()
+    }
+
+    fn pops(&self) -> u64 {
+        self.classes.iter().map(|c| c.pops.load(Ordering::Relaxed)).sum()
+    }
+}
+
+/// Global string buffer pool shared by every `ffi_allocate_string`/`ffi_free_string` call
+static STRING_POOL: StringPool = StringPool::new();
+
+/// Acquire a raw byte buffer with at least `min_capacity` bytes of free space, reusing a
+/// pooled allocation (see [`StringPool::pop`]) when one is available instead of going
+/// through the global allocator
+///
+/// The returned buffer is empty (`len() == 0`) regardless of what it previously held.
+pub fn pooled_buffer(min_capacity: usize) -> Vec<u8> {
+    STRING_POOL.pop(min_capacity).unwrap_or_else(|| {
+        let rounded = SIZE_CLASSES
+            .iter()
+            .copied()
+            .find(|&floor| floor >= min_capacity)
+            .unwrap_or(min_capacity);
+        Vec::with_capacity(rounded)
+    })
+}
+
+/// Return a raw byte buffer to the pool instead of letting it deallocate
+pub fn release_pooled_buffer(buffer: Vec<u8>) {
+    STRING_POOL.push(buffer);
+}
+
+/// Pool-aware equivalent of `CString::new`, reusing a pooled buffer when one is available
+///
+/// Returns `None` if `s` contains an interior NUL byte, mirroring `CString::new`'s failure
+/// mode.
+pub fn pooled_cstring(s: &str) -> Option<CString> {
+    if s.as_bytes().contains(&0) {
+        return None;
+    }
+
+    let needed = s.len() + 1; // +1 for the NUL terminator
+    let mut buffer = pooled_buffer(needed);
+
+    buffer.extend_from_slice(s.as_bytes());
+    buffer.push(0);
+
+    // Safety: `buffer` holds exactly `s`'s bytes (checked above to contain no interior
+    // NUL) followed by a single trailing NUL.
+    CString::from_vec_with_nul(buffer).ok()
+}
+
+/// Return a `CString`'s backing buffer to the pool instead of letting it deallocate
+pub fn release_pooled_cstring(s: CString) {
+    release_pooled_buffer(s.into_bytes_with_nul());
+}
+
+/// Snapshot of string pool activity, for diagnostics
+#[derive(Debug, Clone, Copy)]
+pub struct StringPoolStats {
+    pub pushes: u64,
+    pub pops: u64,
+}
+
+/// Get current string pool statistics, summed across all size classes
+pub fn string_pool_stats() -> StringPoolStats {
+    StringPoolStats {
+        pushes: STRING_POOL.pushes(),
+        pops: STRING_POOL.pops(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pooled_cstring_roundtrip() {
+        let c_string = pooled_cstring("hello").unwrap();
+        assert_eq!(c_string.to_str().unwrap(), "hello");
+    }
+
+    #[test]
+    fn test_pooled_cstring_rejects_interior_nul() {
+        assert!(pooled_cstring("bad\0string").is_none());
+    }
+
+    #[test]
+    fn test_buffer_is_reused_after_release() {
+        let before = string_pool_stats();
+
+        let c_string = pooled_cstring("reuse me").unwrap();
+        release_pooled_cstring(c_string);
+
+        let after_release = string_pool_stats();
+        assert_eq!(after_release.pushes, before.pushes + 1);
+
+        let _reused = pooled_cstring("reused!!").unwrap();
+        let after_pop = string_pool_stats();
+        assert_eq!(after_pop.pops, after_release.pops + 1);
+    }
+
+    #[test]
+    fn test_pooled_buffer_is_reused_after_release() {
+        let before = string_pool_stats();
+
+        let buffer = pooled_buffer(128);
+        assert!(buffer.is_empty());
+        release_pooled_buffer(buffer);
+
+        let after_release = string_pool_stats();
+        assert_eq!(after_release.pushes, before.pushes + 1);
+
+        let _reused = pooled_buffer(64);
+        let after_pop = string_pool_stats();
+        assert_eq!(after_pop.pops, after_release.pops + 1);
+    }
+
+    #[test]
+    fn test_oversized_buffer_is_not_pooled() {
+        let before = string_pool_stats();
+
+        let huge = "x".repeat(MAX_POOLED_CAPACITY + 1);
+        let c_string = pooled_cstring(&huge).unwrap();
+        release_pooled_cstring(c_string);
+
+        let after = string_pool_stats();
+        assert_eq!(after.pushes, before.pushes);
+    }
+
+    #[test]
+    fn test_requests_are_routed_to_the_matching_size_class() {
+        // A request for 200 bytes should round up to the 256-byte class and be satisfied
+        // by a buffer released from that same class, not the 64-byte one.
+        let small = pooled_buffer(50);
+        release_pooled_buffer(small);
+        let medium = pooled_buffer(200);
+        assert!(medium.capacity() >= 256);
+        release_pooled_buffer(medium);
+
+        let before = string_pool_stats();
+        let reused = pooled_buffer(200);
+        assert!(reused.capacity() >= 200);
+        let after = string_pool_stats();
+        assert_eq!(after.pops, before.pops + 1);
+    }
+}
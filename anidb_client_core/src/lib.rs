@@ -5,6 +5,7 @@
 
 pub mod api;
 pub mod batch_processor;
+pub mod buf_mut;
 pub mod buffer;
 #[cfg(feature = "database")]
 pub mod database;
@@ -13,6 +14,7 @@ pub mod ffi;
 pub mod ffi_inline;
 pub mod ffi_memory;
 pub mod ffi_optimization;
+pub mod ffi_string_pool;
 pub mod file_io;
 pub mod file_processing;
 pub mod hashing;
@@ -4,9 +4,18 @@
 //! in flexible ways to create complex processing pipelines.
 
 use super::ProcessingStage;
-use crate::Result;
+use crate::error::InternalError;
+use crate::{Error, Result};
 use async_trait::async_trait;
+use bytes::{Bytes, BytesMut};
 use std::fmt::Debug;
+use std::io::Write;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use tempfile::NamedTempFile;
+use tokio::io::{AsyncRead, ReadBuf};
+use tokio::sync::{mpsc, oneshot};
 
 /// A stage that conditionally applies another stage based on a predicate
 pub struct ConditionalStage<P>
@@ -49,8 +58,8 @@ impl<P> ProcessingStage for ConditionalStage<P>
 where
     P: Fn(&[u8]) -> bool + Send + Sync,
 {
-    async fn process(&mut self, chunk: &[u8]) -> Result<()> {
-        if (self.predicate)(chunk) {
+    async fn process(&mut self, chunk: Bytes) -> Result<()> {
+        if (self.predicate)(&chunk) {
             self.inner.process(chunk).await
         } else {
             Ok(())
@@ -70,45 +79,212 @@ where
     }
 }
 
-/// A stage that applies multiple stages in parallel
-#[derive(Debug)]
+/// Concurrency backend [`ParallelStage`] uses to drive its inner stages
+///
+/// `Tokio` is the right default for stages that do their own async I/O; `Rayon` suits
+/// purely CPU-bound stages (e.g. digest calculation) where blocking a worker thread is
+/// fine; it spawns one `rayon::scope` job per stage for every chunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParallelBackend {
+    /// Give each inner stage its own tokio task, fed over an mpsc channel
+    #[default]
+    Tokio,
+    /// Drive every inner stage inside a single `rayon::scope` call, blocking on each
+    /// stage's future
+    Rayon,
+}
+
+/// Command sent to a tokio-mode stage worker, paired with a reply channel so the caller
+/// can await completion and see the first error
+enum StageCommand {
+    Initialize(u64),
+    Process(Bytes),
+    Finalize,
+}
+
+/// Runs one inner stage to completion on its own tokio task, driven entirely by
+/// `StageCommand`s from its channel — this is what lets the tokio backend fan a chunk out
+/// to every stage without a shared `Mutex`: each stage is only ever touched by its own task.
+async fn run_stage_worker(
+    mut stage: Box<dyn ProcessingStage>,
+    mut commands: mpsc::Receiver<(StageCommand, oneshot::Sender<Result<()>>)>,
+) {
+    while let Some((command, reply)) = commands.recv().await {
+        let result = match command {
+            StageCommand::Initialize(total_size) => stage.initialize(total_size).await,
+            StageCommand::Process(chunk) => stage.process(chunk).await,
+            StageCommand::Finalize => stage.finalize().await,
+        };
+        let _ = reply.send(result);
+    }
+}
+
+/// Send the same command (built fresh per recipient, so `Process` commands can carry
+/// their own `Bytes` clone) to every tokio worker and wait for all of them to finish
+async fn send_to_all_tokio(
+    senders: &[mpsc::Sender<(StageCommand, oneshot::Sender<Result<()>>)>],
+    make_command: impl Fn() -> StageCommand,
+) -> Result<()> {
+    let mut replies = Vec::with_capacity(senders.len());
+
+    for tx in senders {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        tx.send((make_command(), reply_tx)).await.map_err(|_| {
+            Error::Internal(InternalError::assertion(
+                "ParallelStage worker task ended unexpectedly",
+            ))
+        })?;
+        replies.push(reply_rx);
+    }
+
+    for reply in replies {
+        reply
+            .await
+            .map_err(|_| {
+                Error::Internal(InternalError::assertion(
+                    "ParallelStage worker task panicked",
+                ))
+            })??;
+    }
+
+    Ok(())
+}
+
+/// A current-thread tokio runtime cached per rayon worker thread, so the rayon backend
+/// can drive each stage's async call to completion without building a fresh runtime on
+/// every invocation
+fn block_on_stage<F: std::future::Future>(future: F) -> F::Output {
+    thread_local! {
+        static RUNTIME: tokio::runtime::Runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to build blocking runtime for a ParallelStage rayon worker");
+    }
+
+    RUNTIME.with(|runtime| runtime.block_on(future))
+}
+
+/// Inner stages behind the backend selected at construction
+enum ParallelStages {
+    Tokio(Vec<mpsc::Sender<(StageCommand, oneshot::Sender<Result<()>>)>>),
+    Rayon(Vec<Box<dyn ProcessingStage>>),
+}
+
+/// A stage that drives multiple inner stages concurrently over the same chunk stream
+///
+/// Useful for multi-hash file identification (ed2k, md5, sha1, crc32 over the same byte
+/// stream), where each inner stage's work should overlap instead of running one after
+/// another.
 pub struct ParallelStage {
-    stages: Vec<Box<dyn ProcessingStage>>,
+    stages: ParallelStages,
     name: String,
 }
 
+impl Debug for ParallelStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParallelStage")
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
 impl ParallelStage {
-    /// Create a new parallel stage
+    /// Create a new parallel stage using the default ([`ParallelBackend::Tokio`]) backend
     pub fn new(stages: Vec<Box<dyn ProcessingStage>>) -> Self {
-        let stage_names: Vec<_> = stages.iter().map(|s| s.name()).collect();
+        Self::with_backend(stages, ParallelBackend::default())
+    }
+
+    /// Create a new parallel stage using the given concurrency backend
+    pub fn with_backend(stages: Vec<Box<dyn ProcessingStage>>, backend: ParallelBackend) -> Self {
+        let stage_names: Vec<_> = stages.iter().map(|s| s.name().to_string()).collect();
         let name = format!("Parallel[{}]", stage_names.join(", "));
+
+        let stages = match backend {
+            ParallelBackend::Tokio => {
+                let mut senders = Vec::with_capacity(stages.len());
+                for stage in stages {
+                    // Small bounded queue: back-pressure a fast stage against a slow one
+                    // instead of letting chunks pile up unbounded in a worker's mailbox.
+                    let (tx, rx) = mpsc::channel(1);
+                    tokio::spawn(run_stage_worker(stage, rx));
+                    senders.push(tx);
+                }
+                ParallelStages::Tokio(senders)
+            }
+            ParallelBackend::Rayon => ParallelStages::Rayon(stages),
+        };
+
         Self { stages, name }
     }
 }
 
 #[async_trait]
 impl ProcessingStage for ParallelStage {
-    async fn process(&mut self, chunk: &[u8]) -> Result<()> {
-        // Process all stages (sequentially for now, true parallelism would require Arc<Mutex>)
-        for stage in &mut self.stages {
-            stage.process(chunk).await?;
+    async fn process(&mut self, chunk: Bytes) -> Result<()> {
+        match &mut self.stages {
+            ParallelStages::Tokio(senders) => {
+                send_to_all_tokio(senders, || StageCommand::Process(chunk.clone())).await
+            }
+            ParallelStages::Rayon(stages) => {
+                let mut results: Vec<Result<()>> = Vec::with_capacity(stages.len());
+                results.resize_with(stages.len(), || Ok(()));
+
+                rayon::scope(|scope| {
+                    for (stage, slot) in stages.iter_mut().zip(results.iter_mut()) {
+                        let chunk = chunk.clone();
+                        scope.spawn(move |_| {
+                            *slot = block_on_stage(stage.process(chunk));
+                        });
+                    }
+                });
+
+                results.into_iter().collect()
+            }
         }
-
-        Ok(())
     }
 
     async fn initialize(&mut self, total_size: u64) -> Result<()> {
-        for stage in &mut self.stages {
-            stage.initialize(total_size).await?;
+        match &mut self.stages {
+            ParallelStages::Tokio(senders) => {
+                send_to_all_tokio(senders, || StageCommand::Initialize(total_size)).await
+            }
+            ParallelStages::Rayon(stages) => {
+                let mut results: Vec<Result<()>> = Vec::with_capacity(stages.len());
+                results.resize_with(stages.len(), || Ok(()));
+
+                rayon::scope(|scope| {
+                    for (stage, slot) in stages.iter_mut().zip(results.iter_mut()) {
+                        scope.spawn(move |_| {
+                            *slot = block_on_stage(stage.initialize(total_size));
+                        });
+                    }
+                });
+
+                results.into_iter().collect()
+            }
         }
-        Ok(())
     }
 
     async fn finalize(&mut self) -> Result<()> {
-        for stage in &mut self.stages {
-            stage.finalize().await?;
+        match &mut self.stages {
+            ParallelStages::Tokio(senders) => {
+                send_to_all_tokio(senders, || StageCommand::Finalize).await
+            }
+            ParallelStages::Rayon(stages) => {
+                let mut results: Vec<Result<()>> = Vec::with_capacity(stages.len());
+                results.resize_with(stages.len(), || Ok(()));
+
+                rayon::scope(|scope| {
+                    for (stage, slot) in stages.iter_mut().zip(results.iter_mut()) {
+                        scope.spawn(move |_| {
+                            *slot = block_on_stage(stage.finalize());
+                        });
+                    }
+                });
+
+                results.into_iter().collect()
+            }
         }
-        Ok(())
     }
 
     fn name(&self) -> &str {
@@ -157,9 +333,9 @@ impl<T> ProcessingStage for TransformStage<T>
 where
     T: Fn(&[u8]) -> Vec<u8> + Send + Sync,
 {
-    async fn process(&mut self, chunk: &[u8]) -> Result<()> {
-        let transformed = (self.transform)(chunk);
-        self.inner.process(&transformed).await
+    async fn process(&mut self, chunk: Bytes) -> Result<()> {
+        let transformed = (self.transform)(&chunk);
+        self.inner.process(Bytes::from(transformed)).await
     }
 
     async fn initialize(&mut self, total_size: u64) -> Result<()> {
@@ -179,7 +355,7 @@ where
 #[derive(Debug)]
 pub struct BufferingStage {
     inner: Box<dyn ProcessingStage>,
-    buffer: Vec<u8>,
+    buffer: BytesMut,
     buffer_size: usize,
     name: String,
 }
@@ -190,7 +366,7 @@ impl BufferingStage {
         let name = format!("Buffering[{}, {}KB]", inner.name(), buffer_size / 1024);
         Self {
             inner,
-            buffer: Vec::with_capacity(buffer_size),
+            buffer: BytesMut::with_capacity(buffer_size),
             buffer_size,
             name,
         }
@@ -199,13 +375,14 @@ impl BufferingStage {
 
 #[async_trait]
 impl ProcessingStage for BufferingStage {
-    async fn process(&mut self, chunk: &[u8]) -> Result<()> {
-        self.buffer.extend_from_slice(chunk);
+    async fn process(&mut self, chunk: Bytes) -> Result<()> {
+        self.buffer.extend_from_slice(&chunk);
 
-        // Process when buffer is full
+        // Process when buffer is full. `split_to` hands the inner stage a `Bytes` slice
+        // sharing the buffer's backing allocation instead of a freshly copied `Vec`.
         while self.buffer.len() >= self.buffer_size {
-            let process_chunk = self.buffer.drain(..self.buffer_size).collect::<Vec<_>>();
-            self.inner.process(&process_chunk).await?;
+            let piece = self.buffer.split_to(self.buffer_size).freeze();
+            self.inner.process(piece).await?;
         }
 
         Ok(())
@@ -219,8 +396,8 @@ impl ProcessingStage for BufferingStage {
     async fn finalize(&mut self) -> Result<()> {
         // Process any remaining data in the buffer
         if !self.buffer.is_empty() {
-            let remaining = std::mem::take(&mut self.buffer);
-            self.inner.process(&remaining).await?;
+            let remaining = std::mem::take(&mut self.buffer).freeze();
+            self.inner.process(remaining).await?;
         }
 
         self.inner.finalize().await
@@ -256,7 +433,7 @@ impl RateLimitedStage {
 
 #[async_trait]
 impl ProcessingStage for RateLimitedStage {
-    async fn process(&mut self, chunk: &[u8]) -> Result<()> {
+    async fn process(&mut self, chunk: Bytes) -> Result<()> {
         // Check if we need to wait
         if let Some(last) = self.last_process {
             let elapsed = last.elapsed();
@@ -286,6 +463,339 @@ impl ProcessingStage for RateLimitedStage {
     }
 }
 
+/// Message sent to a [`PipelinedStage`]'s consumer task over its bounded queue
+enum PipelinedMessage {
+    Initialize(u64),
+    Chunk(Bytes),
+}
+
+/// Drains a pipelined stage's queue, applying each message to the inner stage in order
+///
+/// Returns as soon as the inner stage errors (dropping the receiver along with the rest
+/// of the queue), otherwise runs until the queue is closed and drained, then finalizes.
+async fn run_pipelined_worker(
+    mut stage: Box<dyn ProcessingStage>,
+    mut queue: mpsc::Receiver<PipelinedMessage>,
+) -> Result<()> {
+    while let Some(message) = queue.recv().await {
+        match message {
+            PipelinedMessage::Initialize(total_size) => stage.initialize(total_size).await?,
+            PipelinedMessage::Chunk(chunk) => stage.process(chunk).await?,
+        }
+    }
+
+    stage.finalize().await
+}
+
+/// A stage that runs its inner stage on its own task, decoupling the producer (e.g. a
+/// disk reader) from the consumer's processing speed
+///
+/// Chunks are handed off over a bounded queue of `capacity` entries as cheap `Bytes`
+/// clones: `process` enqueues and awaits space, so a fast producer blocks instead of
+/// piling up unbounded memory once the queue is full, while a slow inner stage (e.g.
+/// hashing) keeps draining it on its own task. `finalize` closes the queue, waits for
+/// the consumer to drain whatever is left and run the inner stage's own `finalize`,
+/// then surfaces any error it hit.
+pub struct PipelinedStage {
+    queue: Option<mpsc::Sender<PipelinedMessage>>,
+    worker: Option<tokio::task::JoinHandle<Result<()>>>,
+    name: String,
+}
+
+impl Debug for PipelinedStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PipelinedStage")
+            .field("name", &self.name)
+            .field("closed", &self.queue.is_none())
+            .finish()
+    }
+}
+
+impl PipelinedStage {
+    /// Create a new pipelined stage, immediately spawning the inner stage's consumer task
+    pub fn new(inner: Box<dyn ProcessingStage>, capacity: usize) -> Self {
+        let name = format!("Pipelined[{}, {}]", inner.name(), capacity);
+        let (queue, rx) = mpsc::channel(capacity);
+        let worker = tokio::spawn(run_pipelined_worker(inner, rx));
+
+        Self {
+            queue: Some(queue),
+            worker: Some(worker),
+            name,
+        }
+    }
+
+    /// Send a message to the consumer task, awaiting space in the bounded queue
+    async fn send(&self, message: PipelinedMessage) -> Result<()> {
+        let queue = self.queue.as_ref().ok_or_else(|| {
+            Error::Internal(InternalError::assertion(
+                "PipelinedStage used after finalize",
+            ))
+        })?;
+
+        queue.send(message).await.map_err(|_| {
+            Error::Internal(InternalError::assertion(
+                "PipelinedStage consumer task ended unexpectedly",
+            ))
+        })
+    }
+}
+
+#[async_trait]
+impl ProcessingStage for PipelinedStage {
+    async fn process(&mut self, chunk: Bytes) -> Result<()> {
+        self.send(PipelinedMessage::Chunk(chunk)).await
+    }
+
+    async fn initialize(&mut self, total_size: u64) -> Result<()> {
+        self.send(PipelinedMessage::Initialize(total_size)).await
+    }
+
+    async fn finalize(&mut self) -> Result<()> {
+        // Dropping the sender closes the queue, letting the consumer's `recv` drain
+        // whatever is left before it sees the end and runs the inner stage's finalize.
+        self.queue.take();
+
+        match self.worker.take() {
+            Some(handle) => handle.await.map_err(|err| {
+                Error::Internal(InternalError::assertion(format!(
+                    "PipelinedStage consumer task panicked: {err}"
+                )))
+            })?,
+            None => Ok(()),
+        }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Where a [`CacheStage`]'s recorded bytes currently live
+enum Backing {
+    /// Still within the spill threshold: a rope of the chunks recorded so far
+    Memory(Vec<Bytes>),
+    /// Past the threshold: every byte recorded so far (and everything after) lives here
+    Spilled(NamedTempFile),
+}
+
+/// Shared, lockable state behind a [`CacheStage`], so readers can be handed a clone of
+/// the `Arc` and stay valid past the stage itself being dropped
+struct CacheState {
+    backing: Backing,
+    spill_threshold: u64,
+    total_len: u64,
+    finalized: bool,
+}
+
+impl CacheState {
+    fn new(spill_threshold: u64) -> Self {
+        Self {
+            backing: Backing::Memory(Vec::new()),
+            spill_threshold,
+            total_len: 0,
+            finalized: false,
+        }
+    }
+
+    /// Append a chunk to the rope, spilling to a temp file the moment this chunk would
+    /// push the in-memory total past `spill_threshold`
+    fn record(&mut self, chunk: &Bytes) -> Result<()> {
+        if self.finalized {
+            return Err(Error::Internal(InternalError::assertion(
+                "CacheStage cache is already finalized and immutable; writes are rejected",
+            )));
+        }
+
+        match &mut self.backing {
+            Backing::Memory(segments) => {
+                if self.total_len + chunk.len() as u64 > self.spill_threshold {
+                    let mut file = NamedTempFile::new()?;
+                    for segment in segments.iter() {
+                        file.write_all(segment)?;
+                    }
+                    file.write_all(chunk)?;
+                    self.backing = Backing::Spilled(file);
+                } else {
+                    segments.push(chunk.clone());
+                }
+            }
+            Backing::Spilled(file) => file.write_all(chunk)?,
+        }
+
+        self.total_len += chunk.len() as u64;
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> Result<()> {
+        if let Backing::Spilled(file) = &mut self.backing {
+            file.flush()?;
+        }
+        self.finalized = true;
+        Ok(())
+    }
+}
+
+/// A replayable view over the bytes recorded by a [`CacheStage`], tracking its own
+/// read offset independently of any other reader over the same cache
+pub struct CacheReader {
+    inner: CacheReaderInner,
+}
+
+enum CacheReaderInner {
+    Memory { segments: Vec<Bytes>, offset: u64 },
+    File(tokio::fs::File),
+}
+
+impl AsyncRead for CacheReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        match &mut self.get_mut().inner {
+            CacheReaderInner::Memory { segments, offset } => {
+                let mut skip = *offset;
+                for segment in segments.iter() {
+                    let len = segment.len() as u64;
+                    if skip >= len {
+                        skip -= len;
+                        continue;
+                    }
+
+                    let available = &segment[skip as usize..];
+                    let to_copy = available.len().min(buf.remaining());
+                    buf.put_slice(&available[..to_copy]);
+                    *offset += to_copy as u64;
+                    skip = 0;
+
+                    if buf.remaining() == 0 {
+                        break;
+                    }
+                }
+                Poll::Ready(Ok(()))
+            }
+            CacheReaderInner::File(file) => Pin::new(file).poll_read(cx, buf),
+        }
+    }
+}
+
+/// A stage that records the entire byte stream flowing through it while still
+/// forwarding each chunk to its inner stage, so the stream can be replayed afterward
+/// (e.g. hash once, then scan the same bytes for container metadata without re-reading
+/// from the network)
+///
+/// Recorded chunks are kept in memory as a growable rope of segments until the total
+/// exceeds `spill_threshold`, at which point everything recorded so far — and
+/// everything recorded after — is written to a temporary file instead, so arbitrarily
+/// large streams never exhaust RAM. `finalize` flushes any spill file and makes the
+/// cache immutable: further `process` calls still forward to the inner stage, but
+/// [`CacheState::record`] rejects the write, since a reader may already be replaying it.
+pub struct CacheStage {
+    inner: Box<dyn ProcessingStage>,
+    state: Arc<Mutex<CacheState>>,
+    spill_threshold: u64,
+    name: String,
+}
+
+impl CacheStage {
+    /// Spill to a temp file once the cache exceeds 16MB in memory
+    pub const DEFAULT_SPILL_THRESHOLD: u64 = 16 * 1024 * 1024;
+
+    /// Create a cache stage using the default spill threshold
+    pub fn new(inner: Box<dyn ProcessingStage>) -> Self {
+        Self::with_spill_threshold(inner, Self::DEFAULT_SPILL_THRESHOLD)
+    }
+
+    /// Create a cache stage that spills to a temp file past `spill_threshold` bytes
+    pub fn with_spill_threshold(inner: Box<dyn ProcessingStage>, spill_threshold: u64) -> Self {
+        let name = format!("Cache[{}]", inner.name());
+        Self {
+            inner,
+            state: Arc::new(Mutex::new(CacheState::new(spill_threshold))),
+            spill_threshold,
+            name,
+        }
+    }
+
+    /// Total bytes recorded so far
+    pub fn total_len(&self) -> u64 {
+        self.state.lock().unwrap().total_len
+    }
+
+    /// Whether `finalize` has run and the cache is now immutable
+    pub fn is_finalized(&self) -> bool {
+        self.state.lock().unwrap().finalized
+    }
+
+    /// Open a fresh, independently-offset reader over the bytes recorded so far
+    ///
+    /// Safe to call concurrently, and safe after `finalize`. Called mid-stream on an
+    /// in-memory cache it yields a snapshot of what's been recorded up to that point; once
+    /// the cache has spilled, the reader opens the spill file itself and also sees bytes
+    /// recorded after it was opened.
+    pub async fn reader(&self) -> Result<CacheReader> {
+        enum Snapshot {
+            Memory(Vec<Bytes>),
+            File(std::path::PathBuf),
+        }
+
+        let snapshot = {
+            let state = self.state.lock().unwrap();
+            match &state.backing {
+                Backing::Memory(segments) => Snapshot::Memory(segments.clone()),
+                Backing::Spilled(file) => Snapshot::File(file.path().to_path_buf()),
+            }
+        };
+
+        let inner = match snapshot {
+            Snapshot::Memory(segments) => CacheReaderInner::Memory { segments, offset: 0 },
+            Snapshot::File(path) => CacheReaderInner::File(tokio::fs::File::open(path).await?),
+        };
+
+        Ok(CacheReader { inner })
+    }
+}
+
+impl Debug for CacheStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.state.lock().unwrap();
+        f.debug_struct("CacheStage")
+            .field("name", &self.name)
+            .field("spill_threshold", &self.spill_threshold)
+            .field("total_len", &state.total_len)
+            .field("spilled", &matches!(state.backing, Backing::Spilled(_)))
+            .field("finalized", &state.finalized)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl ProcessingStage for CacheStage {
+    async fn process(&mut self, chunk: Bytes) -> Result<()> {
+        // The inner stage must still see every chunk even once the cache is finalized and
+        // `record` starts rejecting writes, matching the documented behavior above.
+        let record_result = self.state.lock().unwrap().record(&chunk);
+        self.inner.process(chunk).await?;
+        record_result
+    }
+
+    async fn initialize(&mut self, total_size: u64) -> Result<()> {
+        // A fresh run replaces the cache outright rather than appending to the old one.
+        *self.state.lock().unwrap() = CacheState::new(self.spill_threshold);
+        self.inner.initialize(total_size).await
+    }
+
+    async fn finalize(&mut self) -> Result<()> {
+        self.state.lock().unwrap().finalize()?;
+        self.inner.finalize().await
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
 /// Extension trait for composing stages
 pub trait StageExt: ProcessingStage + Sized {
     /// Apply this stage conditionally based on a predicate
@@ -321,6 +831,24 @@ pub trait StageExt: ProcessingStage + Sized {
     {
         RateLimitedStage::new(Box::new(self), max_per_second)
     }
+
+    /// Run this stage on its own task, fed by a bounded queue of `capacity` chunks, to
+    /// decouple a fast producer from a slower consumer without unbounded memory growth
+    fn pipelined(self, capacity: usize) -> PipelinedStage
+    where
+        Self: 'static,
+    {
+        PipelinedStage::new(Box::new(self), capacity)
+    }
+
+    /// Tee the byte stream into a replayable cache, spilling to disk past
+    /// `spill_threshold` bytes, while still forwarding every chunk to this stage
+    fn cached(self, spill_threshold: u64) -> CacheStage
+    where
+        Self: 'static,
+    {
+        CacheStage::with_spill_threshold(Box::new(self), spill_threshold)
+    }
 }
 
 // Implement StageExt for all ProcessingStage types
@@ -352,7 +880,7 @@ mod tests {
 
     #[async_trait]
     impl ProcessingStage for CountingStage {
-        async fn process(&mut self, chunk: &[u8]) -> Result<()> {
+        async fn process(&mut self, chunk: Bytes) -> Result<()> {
             let len = chunk.len();
             self.count.fetch_add(len, Ordering::SeqCst);
             Ok(())
@@ -363,6 +891,10 @@ mod tests {
         }
     }
 
+    fn bytes_of(len: usize) -> Bytes {
+        Bytes::from(vec![0u8; len])
+    }
+
     #[tokio::test]
     async fn test_conditional_stage() {
         let counting = CountingStage::new();
@@ -371,10 +903,10 @@ mod tests {
         // Only process chunks larger than 10 bytes
         let mut conditional = counting.when(|chunk| chunk.len() > 10);
 
-        conditional.process(&[0; 5]).await.unwrap(); // Should not count
+        conditional.process(bytes_of(5)).await.unwrap(); // Should not count
         assert_eq!(count_ref.load(Ordering::SeqCst), 0);
 
-        conditional.process(&[0; 15]).await.unwrap(); // Should count
+        conditional.process(bytes_of(15)).await.unwrap(); // Should count
         assert_eq!(count_ref.load(Ordering::SeqCst), 15);
     }
 
@@ -391,7 +923,7 @@ mod tests {
             doubled
         });
 
-        transform.process(&[0; 10]).await.unwrap();
+        transform.process(bytes_of(10)).await.unwrap();
         assert_eq!(count_ref.load(Ordering::SeqCst), 20); // 10 * 2
     }
 
@@ -407,17 +939,17 @@ mod tests {
         assert_eq!(count_ref.load(Ordering::SeqCst), 0, "After init");
 
         // These should be buffered
-        buffering.process(&[0; 5]).await.unwrap();
+        buffering.process(bytes_of(5)).await.unwrap();
         assert_eq!(count_ref.load(Ordering::SeqCst), 0, "After 5 bytes");
 
-        buffering.process(&[0; 5]).await.unwrap();
+        buffering.process(bytes_of(5)).await.unwrap();
         assert_eq!(count_ref.load(Ordering::SeqCst), 0, "After 10 bytes");
 
-        buffering.process(&[0; 5]).await.unwrap();
+        buffering.process(bytes_of(5)).await.unwrap();
         assert_eq!(count_ref.load(Ordering::SeqCst), 0, "After 15 bytes"); // Not processed yet
 
         // This should trigger processing of 20 bytes
-        buffering.process(&[0; 10]).await.unwrap();
+        buffering.process(bytes_of(10)).await.unwrap();
         assert_eq!(count_ref.load(Ordering::SeqCst), 20, "After 25 bytes");
 
         // Finalize should process remaining 5 bytes
@@ -435,12 +967,130 @@ mod tests {
 
         let mut parallel = ParallelStage::new(vec![Box::new(counting1), Box::new(counting2)]);
 
-        parallel.process(&[0; 10]).await.unwrap();
+        parallel.process(bytes_of(10)).await.unwrap();
+
+        assert_eq!(count1_ref.load(Ordering::SeqCst), 10);
+        assert_eq!(count2_ref.load(Ordering::SeqCst), 10);
+    }
+
+    #[tokio::test]
+    async fn test_parallel_stage_rayon_backend() {
+        let counting1 = CountingStage::new();
+        let count1_ref = counting1.count.clone();
+
+        let counting2 = CountingStage::new();
+        let count2_ref = counting2.count.clone();
+
+        let mut parallel = ParallelStage::with_backend(
+            vec![Box::new(counting1), Box::new(counting2)],
+            ParallelBackend::Rayon,
+        );
+
+        parallel.initialize(100).await.unwrap();
+        parallel.process(bytes_of(10)).await.unwrap();
+        parallel.finalize().await.unwrap();
 
         assert_eq!(count1_ref.load(Ordering::SeqCst), 10);
         assert_eq!(count2_ref.load(Ordering::SeqCst), 10);
     }
 
+    #[derive(Debug)]
+    struct FailingStage;
+
+    #[async_trait]
+    impl ProcessingStage for FailingStage {
+        async fn process(&mut self, _chunk: Bytes) -> Result<()> {
+            Err(Error::Internal(InternalError::assertion(
+                "FailingStage always fails",
+            )))
+        }
+
+        fn name(&self) -> &str {
+            "FailingStage"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pipelined_stage_forwards_chunks_and_finalizes() {
+        let counting = CountingStage::new();
+        let count_ref = counting.count.clone();
+
+        let mut pipelined = counting.pipelined(2);
+
+        pipelined.initialize(20).await.unwrap();
+        pipelined.process(bytes_of(5)).await.unwrap();
+        pipelined.process(bytes_of(10)).await.unwrap();
+        pipelined.finalize().await.unwrap();
+
+        assert_eq!(count_ref.load(Ordering::SeqCst), 15);
+    }
+
+    #[tokio::test]
+    async fn test_pipelined_stage_surfaces_inner_error_on_finalize() {
+        let mut pipelined = FailingStage.pipelined(4);
+
+        // The consumer task processes this asynchronously; the error surfaces once we
+        // join it in `finalize`, not necessarily from this `process` call itself.
+        let _ = pipelined.process(bytes_of(1)).await;
+        assert!(pipelined.finalize().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_cache_stage_records_and_replays_from_memory() {
+        use tokio::io::AsyncReadExt;
+
+        let counting = CountingStage::new();
+        let mut cached = counting.cached(1024);
+
+        cached.initialize(10).await.unwrap();
+        cached.process(Bytes::from_static(b"hello ")).await.unwrap();
+        cached.process(Bytes::from_static(b"world")).await.unwrap();
+        cached.finalize().await.unwrap();
+
+        assert_eq!(cached.total_len(), 11);
+
+        let mut reader = cached.reader().await.unwrap();
+        let mut replayed = Vec::new();
+        reader.read_to_end(&mut replayed).await.unwrap();
+        assert_eq!(replayed, b"hello world");
+    }
+
+    #[tokio::test]
+    async fn test_cache_stage_spills_past_threshold_and_still_replays() {
+        use tokio::io::AsyncReadExt;
+
+        let counting = CountingStage::new();
+        // A tiny threshold forces a spill on the second chunk.
+        let mut cached = counting.cached(4);
+
+        cached.process(bytes_of(4)).await.unwrap();
+        cached.process(Bytes::from_static(b"spilled")).await.unwrap();
+        cached.finalize().await.unwrap();
+
+        assert_eq!(cached.total_len(), 11);
+
+        let mut reader = cached.reader().await.unwrap();
+        let mut replayed = Vec::new();
+        reader.read_to_end(&mut replayed).await.unwrap();
+        assert_eq!(replayed.len(), 11);
+        assert_eq!(&replayed[4..], b"spilled");
+    }
+
+    #[tokio::test]
+    async fn test_cache_stage_rejects_writes_after_finalize() {
+        let counting = CountingStage::new();
+        let count_ref = counting.count.clone();
+        let mut cached = counting.cached(1024);
+
+        cached.process(bytes_of(3)).await.unwrap();
+        cached.finalize().await.unwrap();
+        assert!(cached.is_finalized());
+
+        // The inner stage still sees the chunk; only the cache recording rejects it.
+        assert!(cached.process(bytes_of(3)).await.is_err());
+        assert_eq!(count_ref.load(Ordering::SeqCst), 6);
+    }
+
     #[tokio::test]
     async fn test_chained_combinators() {
         // Test that combinators can be chained together
@@ -460,13 +1110,13 @@ mod tests {
             })
             .when(|chunk| chunk.len() >= 5); // Only process chunks >= 5 bytes
 
-        chained.process(&[0; 3]).await.unwrap(); // Filtered out (< 5)
+        chained.process(bytes_of(3)).await.unwrap(); // Filtered out (< 5)
         assert_eq!(count_ref.load(Ordering::SeqCst), 0);
 
-        chained.process(&[0; 8]).await.unwrap(); // Passes filter, transformed to 5, buffered
+        chained.process(bytes_of(8)).await.unwrap(); // Passes filter, transformed to 5, buffered
         assert_eq!(count_ref.load(Ordering::SeqCst), 0);
 
-        chained.process(&[0; 7]).await.unwrap(); // Passes filter, transformed to 5, now have 10 in buffer
+        chained.process(bytes_of(7)).await.unwrap(); // Passes filter, transformed to 5, now have 10 in buffer
         assert_eq!(count_ref.load(Ordering::SeqCst), 10);
     }
 }
@@ -2,7 +2,7 @@
 //!
 //! This stage calculates hashes for data chunks as they flow through the pipeline.
 
-use super::ProcessingStage;
+use super::ByteSliceStage;
 use crate::Result;
 use crate::hashing::{HashAlgorithm, HashAlgorithmExt, StreamingHasher};
 use crate::progress::{ProgressProvider, ProgressUpdate};
@@ -199,7 +199,7 @@ struct ParallelState {
 }
 
 #[async_trait]
-impl ProcessingStage for HashingStage {
+impl ByteSliceStage for HashingStage {
     async fn process(&mut self, chunk: &[u8]) -> Result<()> {
         if let Some(p) = &mut self.parallel {
             // Broadcast chunk to workers using shared buffer
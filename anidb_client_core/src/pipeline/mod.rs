@@ -5,27 +5,34 @@
 
 use crate::Result;
 use async_trait::async_trait;
+use bytes::{Buf, Bytes};
 use std::fmt::Debug;
 
 mod combinators;
 mod hashing;
+mod parallel_digest;
 mod progress;
 mod streaming;
 mod validation;
 
 pub use combinators::{
-    BufferingStage, ConditionalStage, ParallelStage, RateLimitedStage, StageExt, TransformStage,
+    BufferingStage, CacheReader, CacheStage, ConditionalStage, ParallelBackend, ParallelStage,
+    PipelinedStage, RateLimitedStage, StageExt, TransformStage,
 };
 pub use hashing::HashingStage;
+pub use parallel_digest::ParallelDigestStage;
 pub use progress::ProgressStage;
 pub use streaming::{StreamingPipeline, StreamingPipelineBuilder};
 pub use validation::ValidationStage;
 
 /// Core trait for pipeline processing stages
 ///
-/// Each stage processes chunks of data as they flow through the pipeline.
-/// Stages are composable and can be chained together to form complex
-/// processing workflows.
+/// Each stage processes chunks of data as they flow through the pipeline. Chunks are
+/// passed as [`Bytes`], a reference-counted, cheaply-cloneable byte buffer: a chain of
+/// combinators (buffering, fan-out, pipelining) can each hold their own clone of the
+/// same chunk without copying it, and `split_to`/slicing produce further sub-slices
+/// that share the same backing allocation. Stages are composable and can be chained
+/// together to form complex processing workflows.
 #[async_trait]
 pub trait ProcessingStage: Send + Sync + Debug {
     /// Process a chunk of data
@@ -35,7 +42,7 @@ pub trait ProcessingStage: Send + Sync + Debug {
     ///
     /// # Returns
     /// Ok(()) if processing succeeded, Error otherwise
-    async fn process(&mut self, chunk: &[u8]) -> Result<()>;
+    async fn process(&mut self, chunk: Bytes) -> Result<()>;
 
     /// Called when processing starts
     ///
@@ -68,6 +75,62 @@ pub trait ProcessingStage: Send + Sync + Debug {
     }
 }
 
+/// Transitional trait for stages written against a plain `&[u8]` chunk
+///
+/// Most stages only ever read their chunk; implementing this instead of
+/// [`ProcessingStage`] directly avoids threading `Bytes` through every
+/// byte-counting/hashing/validating stage for no benefit. The blanket impl below
+/// adapts it to `ProcessingStage` by borrowing the `Bytes`' backing slice, so no chunk
+/// data is copied. Stages that actually want to hold onto or cheaply re-clone the
+/// chunk (buffering, fan-out, pipelining) should implement `ProcessingStage` directly.
+#[async_trait]
+pub trait ByteSliceStage: Send + Sync + Debug {
+    /// Process a chunk of data, see [`ProcessingStage::process`]
+    async fn process(&mut self, chunk: &[u8]) -> Result<()>;
+
+    /// See [`ProcessingStage::initialize`]
+    async fn initialize(&mut self, total_size: u64) -> Result<()> {
+        let _ = total_size;
+        Ok(())
+    }
+
+    /// See [`ProcessingStage::finalize`]
+    async fn finalize(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// See [`ProcessingStage::name`]
+    fn name(&self) -> &str;
+
+    /// See [`ProcessingStage::as_any_mut`]
+    fn as_any_mut(&mut self) -> Option<&mut dyn std::any::Any> {
+        None
+    }
+}
+
+#[async_trait]
+impl<T: ByteSliceStage> ProcessingStage for T {
+    async fn process(&mut self, chunk: Bytes) -> Result<()> {
+        ByteSliceStage::process(self, &chunk).await
+    }
+
+    async fn initialize(&mut self, total_size: u64) -> Result<()> {
+        ByteSliceStage::initialize(self, total_size).await
+    }
+
+    async fn finalize(&mut self) -> Result<()> {
+        ByteSliceStage::finalize(self).await
+    }
+
+    fn name(&self) -> &str {
+        ByteSliceStage::name(self)
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn std::any::Any> {
+        ByteSliceStage::as_any_mut(self)
+    }
+}
+
 /// Configuration for pipeline execution
 #[derive(Debug, Clone)]
 pub struct PipelineConfig {
@@ -101,3 +164,59 @@ pub struct PipelineStats {
     /// Throughput in MB/s
     pub throughput_mbps: f64,
 }
+
+/// Iterator adapter that slices a [`bytes::Buf`] into fixed-size [`Bytes`] chunks
+///
+/// Lets a caller that already holds its data as a `Buf` (e.g. a chain of buffers from a
+/// decoder, or anything else that isn't one contiguous slice) feed a pipeline without
+/// first flattening everything into a single allocation.
+pub struct BufChunks<B> {
+    buf: B,
+    chunk_size: usize,
+}
+
+impl<B: bytes::Buf> BufChunks<B> {
+    /// Create an adapter yielding chunks of at most `chunk_size` bytes from `buf`
+    pub fn new(buf: B, chunk_size: usize) -> Self {
+        Self { buf, chunk_size }
+    }
+}
+
+impl<B: bytes::Buf> Iterator for BufChunks<B> {
+    type Item = Bytes;
+
+    fn next(&mut self) -> Option<Bytes> {
+        if !self.buf.has_remaining() {
+            return None;
+        }
+
+        let take = self.chunk_size.min(self.buf.remaining());
+        Some(self.buf.copy_to_bytes(take))
+    }
+}
+
+#[cfg(test)]
+mod buf_chunks_tests {
+    use super::*;
+
+    #[test]
+    fn test_buf_chunks_splits_into_fixed_size_pieces() {
+        let data = Bytes::from_static(b"0123456789");
+        let chunks: Vec<Bytes> = BufChunks::new(data, 4).collect();
+
+        assert_eq!(
+            chunks,
+            vec![
+                Bytes::from_static(b"0123"),
+                Bytes::from_static(b"4567"),
+                Bytes::from_static(b"89"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_buf_chunks_empty_buf_yields_nothing() {
+        let chunks: Vec<Bytes> = BufChunks::new(Bytes::new(), 4).collect();
+        assert!(chunks.is_empty());
+    }
+}
@@ -0,0 +1,265 @@
+//! Parallel chunked-digest stage for block-hash algorithms (ed2k, tree hashes)
+//!
+//! ED2K and TTH both hash the stream as fixed-size blocks and then hash the
+//! concatenation of block digests to get a final result (see
+//! `hashing::algorithms::ed2k`/`tth`), but their [`StreamingHasher`](crate::hashing::traits::StreamingHasher)
+//! implementations hash each block serially as it completes. [`ParallelDigestStage`]
+//! offers the same block-then-hash-of-hashes shape over a raw [`digest::Digest`]
+//! implementor, but dispatches each completed block to a `spawn_blocking` task so
+//! blocks are hashed concurrently instead of one at a time.
+
+use super::ByteSliceStage;
+use crate::Result;
+use crate::error::{Error, InternalError};
+use async_trait::async_trait;
+use digest::{Digest, Output};
+use std::fmt;
+use tokio::task::JoinHandle;
+
+/// Stage that computes a block-hash digest (ed2k/TTH style) using data parallelism
+///
+/// Incoming bytes are buffered into fixed-size blocks (default: the ed2k chunk size,
+/// 9,728,000 bytes). Each time a block fills up, it's handed to a `spawn_blocking`
+/// task that runs `D` over it independently of the pipeline's async task. On
+/// `finalize`, any trailing partial block is hashed, all per-block digests are
+/// collected in block order (regardless of which task finished first), and — unless
+/// there's only a single block, in which case that block's digest *is* the result —
+/// `D` is run once more over the concatenated per-block digests to produce the
+/// condensed hash.
+pub struct ParallelDigestStage<D: Digest + Send + 'static> {
+    block_size: usize,
+    accumulator: Vec<u8>,
+    pending: Vec<JoinHandle<Output<D>>>,
+    block_digests: Vec<Output<D>>,
+    total_bytes: u64,
+    condensed: Option<Output<D>>,
+}
+
+impl<D: Digest + Send + 'static> ParallelDigestStage<D> {
+    /// The ed2k chunk size, used as the default block size
+    pub const DEFAULT_BLOCK_SIZE: usize = 9_728_000;
+
+    /// Create a stage using the default (ed2k) block size
+    pub fn new() -> Self {
+        Self::with_block_size(Self::DEFAULT_BLOCK_SIZE)
+    }
+
+    /// Create a stage with a custom block size
+    ///
+    /// The block size must match between hashing and verification for the
+    /// resulting digests to be comparable, so callers that persist or transmit
+    /// results should keep it fixed.
+    pub fn with_block_size(block_size: usize) -> Self {
+        Self {
+            block_size,
+            accumulator: Vec::with_capacity(block_size),
+            pending: Vec::new(),
+            block_digests: Vec::new(),
+            total_bytes: 0,
+            condensed: None,
+        }
+    }
+
+    /// Dispatch a completed block to a blocking worker task
+    fn spawn_block(block: Vec<u8>) -> JoinHandle<Output<D>> {
+        tokio::task::spawn_blocking(move || {
+            let mut hasher = D::new();
+            hasher.update(&block);
+            hasher.finalize()
+        })
+    }
+
+    /// The ordered per-block digests, so callers can verify partial downloads
+    /// block-by-block rather than waiting on the whole file
+    pub fn block_digests(&self) -> &[Output<D>] {
+        &self.block_digests
+    }
+
+    /// The condensed hash produced by `finalize`, or `None` before it has run
+    pub fn condensed_digest(&self) -> Option<&Output<D>> {
+        self.condensed.as_ref()
+    }
+}
+
+impl<D: Digest + Send + 'static> Default for ParallelDigestStage<D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D: Digest + Send + 'static> fmt::Debug for ParallelDigestStage<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParallelDigestStage")
+            .field("block_size", &self.block_size)
+            .field("completed_blocks", &self.block_digests.len())
+            .field("pending_blocks", &self.pending.len())
+            .field("total_bytes", &self.total_bytes)
+            .field("has_condensed", &self.condensed.is_some())
+            .finish()
+    }
+}
+
+#[async_trait]
+impl<D: Digest + Send + 'static> ByteSliceStage for ParallelDigestStage<D> {
+    async fn process(&mut self, chunk: &[u8]) -> Result<()> {
+        let mut remaining = chunk;
+
+        while !remaining.is_empty() {
+            let space_in_accumulator = self.block_size - self.accumulator.len();
+            let to_copy = remaining.len().min(space_in_accumulator);
+
+            self.accumulator.extend_from_slice(&remaining[..to_copy]);
+            remaining = &remaining[to_copy..];
+
+            if self.accumulator.len() == self.block_size {
+                let block = std::mem::replace(
+                    &mut self.accumulator,
+                    Vec::with_capacity(self.block_size),
+                );
+                self.pending.push(Self::spawn_block(block));
+            }
+        }
+
+        self.total_bytes += chunk.len() as u64;
+        Ok(())
+    }
+
+    async fn initialize(&mut self, _total_size: u64) -> Result<()> {
+        self.accumulator.clear();
+        self.pending.clear();
+        self.block_digests.clear();
+        self.total_bytes = 0;
+        self.condensed = None;
+        Ok(())
+    }
+
+    async fn finalize(&mut self) -> Result<()> {
+        // Collect completed blocks in submission order, regardless of which
+        // spawn_blocking task actually finished first.
+        for handle in self.pending.drain(..) {
+            let digest = handle.await.map_err(|err| {
+                Error::Internal(InternalError::hash_calculation(
+                    "ParallelDigestStage",
+                    &format!("block worker task failed: {err}"),
+                ))
+            })?;
+            self.block_digests.push(digest);
+        }
+
+        if self.total_bytes == 0 {
+            self.condensed = Some(D::new().finalize());
+            return Ok(());
+        }
+
+        // Hash any trailing partial (or, for an exact multiple of block_size,
+        // already-empty) block.
+        if !self.accumulator.is_empty() {
+            let mut hasher = D::new();
+            hasher.update(&self.accumulator);
+            self.block_digests.push(hasher.finalize());
+            self.accumulator.clear();
+        }
+
+        self.condensed = if self.block_digests.len() == 1 {
+            // Single block: its digest *is* the result, matching the ed2k special case.
+            Some(self.block_digests[0].clone())
+        } else {
+            let mut hasher = D::new();
+            for block_digest in &self.block_digests {
+                hasher.update(block_digest);
+            }
+            Some(hasher.finalize())
+        };
+
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "ParallelDigestStage"
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn std::any::Any> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use md4::Md4;
+
+    #[tokio::test]
+    async fn test_single_small_block_returns_block_digest_directly() {
+        let mut stage = ParallelDigestStage::<Md4>::with_block_size(1024);
+        stage.initialize(5).await.unwrap();
+        stage.process(b"hello").await.unwrap();
+        stage.finalize().await.unwrap();
+
+        let mut expected_hasher = Md4::new();
+        expected_hasher.update(b"hello");
+        let expected = expected_hasher.finalize();
+
+        assert_eq!(stage.block_digests().len(), 1);
+        assert_eq!(stage.condensed_digest(), Some(&expected));
+    }
+
+    #[tokio::test]
+    async fn test_multiple_blocks_are_hashed_of_hashes() {
+        let mut stage = ParallelDigestStage::<Md4>::with_block_size(4);
+        stage.initialize(10).await.unwrap();
+        // 4 + 4 + 2 bytes -> two full blocks dispatched, one partial tail
+        stage.process(b"abcdefghij").await.unwrap();
+        stage.finalize().await.unwrap();
+
+        assert_eq!(stage.block_digests().len(), 3);
+
+        let mut hasher = Md4::new();
+        for block_digest in stage.block_digests() {
+            hasher.update(block_digest);
+        }
+        let expected = hasher.finalize();
+        assert_eq!(stage.condensed_digest(), Some(&expected));
+    }
+
+    #[tokio::test]
+    async fn test_block_order_preserved_across_many_blocks() {
+        let mut stage = ParallelDigestStage::<Md4>::with_block_size(2);
+        stage.initialize(8).await.unwrap();
+        stage.process(b"01234567").await.unwrap();
+        stage.finalize().await.unwrap();
+
+        let expected_blocks: Vec<_> = b"01234567"
+            .chunks(2)
+            .map(|chunk| {
+                let mut hasher = Md4::new();
+                hasher.update(chunk);
+                hasher.finalize()
+            })
+            .collect();
+
+        assert_eq!(stage.block_digests(), expected_blocks.as_slice());
+    }
+
+    #[tokio::test]
+    async fn test_empty_input_hashes_empty_data() {
+        let mut stage = ParallelDigestStage::<Md4>::new();
+        stage.initialize(0).await.unwrap();
+        stage.finalize().await.unwrap();
+
+        assert!(stage.block_digests().is_empty());
+        assert_eq!(stage.condensed_digest(), Some(&Md4::new().finalize()));
+    }
+
+    #[tokio::test]
+    async fn test_reinitialize_clears_previous_run_state() {
+        let mut stage = ParallelDigestStage::<Md4>::with_block_size(4);
+        stage.initialize(4).await.unwrap();
+        stage.process(b"abcd").await.unwrap();
+        stage.finalize().await.unwrap();
+        assert_eq!(stage.block_digests().len(), 1);
+
+        stage.initialize(4).await.unwrap();
+        assert!(stage.block_digests().is_empty());
+        assert!(stage.condensed_digest().is_none());
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! This stage reports progress updates as data flows through the pipeline.
 
-use super::ProcessingStage;
+use super::ByteSliceStage;
 use crate::progress::{ProgressProvider, ProgressUpdate};
 use crate::{Error, Result, error::ValidationError};
 use async_trait::async_trait;
@@ -92,7 +92,7 @@ impl ProgressStage {
 }
 
 #[async_trait]
-impl ProcessingStage for ProgressStage {
+impl ByteSliceStage for ProgressStage {
     async fn process(&mut self, chunk: &[u8]) -> Result<()> {
         let chunk_size = chunk.len() as u64;
         self.bytes_processed += chunk_size;
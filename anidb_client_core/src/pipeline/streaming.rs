@@ -2,10 +2,11 @@
 //!
 //! This module provides the main pipeline that composes processing stages.
 
-use super::{PipelineConfig, PipelineStats, ProcessingStage};
+use super::{BufChunks, PipelineConfig, PipelineStats, ProcessingStage};
 use crate::buffer::MemoryTracker;
 use crate::memory::{allocate as mem_allocate, release as mem_release};
 use crate::{Error, Result};
+use bytes::Bytes;
 use std::path::Path;
 use std::time::Instant;
 use tokio::fs::File;
@@ -84,11 +85,13 @@ impl StreamingPipeline {
                 break; // EOF
             }
 
-            let chunk = &buffer[..bytes_read];
+            // One copy out of the read buffer into a ref-counted `Bytes`; every stage
+            // below then gets a cheap clone of it instead of its own copy.
+            let chunk = Bytes::copy_from_slice(&buffer[..bytes_read]);
 
             // Process chunk through all stages
             for stage in &mut self.stages {
-                stage.process(chunk).await.map_err(|e| {
+                stage.process(chunk.clone()).await.map_err(|e| {
                     Error::Internal(crate::error::InternalError::Assertion {
                         message: format!("Stage '{}' failed: {}", stage.name(), e),
                     })
@@ -137,20 +140,17 @@ impl StreamingPipeline {
             throughput_mbps: 0.0,
         };
 
-        // Process data in chunks
-        let mut offset = 0;
-        while offset < data.len() {
-            let chunk_end = (offset + self.config.chunk_size).min(data.len());
-            let chunk = &data[offset..chunk_end];
-
+        // One copy into a ref-counted `Bytes`, then `BufChunks` slices it into
+        // `chunk_size` pieces sharing that same backing allocation (no per-chunk copy).
+        let data = Bytes::copy_from_slice(data);
+        for chunk in BufChunks::new(data, self.config.chunk_size) {
             // Process chunk through all stages
             for stage in &mut self.stages {
-                stage.process(chunk).await?;
+                stage.process(chunk.clone()).await?;
             }
 
             self.stats.bytes_processed += chunk.len() as u64;
             self.stats.chunks_processed += 1;
-            offset = chunk_end;
         }
 
         // Finalize all stages
@@ -2,7 +2,7 @@
 //!
 //! This stage validates data chunks and enforces constraints.
 
-use super::ProcessingStage;
+use super::ByteSliceStage;
 use crate::{Error, Result};
 use async_trait::async_trait;
 
@@ -79,7 +79,7 @@ impl ValidationStage {
 }
 
 #[async_trait]
-impl ProcessingStage for ValidationStage {
+impl ByteSliceStage for ValidationStage {
     async fn process(&mut self, chunk: &[u8]) -> Result<()> {
         // Check empty chunks
         if chunk.is_empty() {
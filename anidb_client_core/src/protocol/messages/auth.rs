@@ -131,56 +131,47 @@ impl AniDBCommand for AuthCommand {
     fn encode(&self) -> Result<String> {
         // Override the default encode to ensure correct parameter order
         // According to API docs: AUTH user={str username}&pass={str password}&protover={int4 apiversion}&client={str clientname}&clientver={int4 clientversion}
-        let mut parts = vec!["AUTH".to_string()];
-
-        // Required parameters in the correct order
-        parts.push(format!(
-            "user={}",
-            crate::protocol::messages::encode_value(&self.user)
-        ));
-        parts.push(format!(
-            "pass={}",
-            crate::protocol::messages::encode_value(&self.pass.expose_secret())
-        ));
-        parts.push(format!(
-            "protover={}",
-            crate::protocol::messages::encode_value(&self.protover)
-        ));
-        parts.push(format!(
-            "client={}",
-            crate::protocol::messages::encode_value(&self.client)
-        ));
-        parts.push(format!(
-            "clientver={}",
-            crate::protocol::messages::encode_value(&self.clientver)
-        ));
+        //
+        // Built with BufMutWriter instead of format!+Vec<String>+join so the required
+        // fields (often the largest, and already copied once by encode_value) only get
+        // copied into the final buffer once instead of through an intermediate per-field
+        // String and a final join allocation.
+        let mut writer = crate::buf_mut::BufMutWriter::with_capacity(128);
+
+        writer.put_slice(b"AUTH user=");
+        writer.put_slice(crate::protocol::messages::encode_value(&self.user).as_bytes());
+        writer.put_slice(b"&pass=");
+        writer.put_slice(crate::protocol::messages::encode_value(&self.pass.expose_secret()).as_bytes());
+        writer.put_slice(b"&protover=");
+        writer.put_slice(crate::protocol::messages::encode_value(&self.protover).as_bytes());
+        writer.put_slice(b"&client=");
+        writer.put_slice(crate::protocol::messages::encode_value(&self.client).as_bytes());
+        writer.put_slice(b"&clientver=");
+        writer.put_slice(crate::protocol::messages::encode_value(&self.clientver).as_bytes());
 
         // Optional parameters
         if let Some(nat) = self.nat {
-            parts.push(format!("nat={nat}"));
+            writer.put_slice(b"&nat=");
+            writer.put_slice(nat.to_string().as_bytes());
         }
         if let Some(comp) = self.comp {
-            parts.push(format!("comp={comp}"));
+            writer.put_slice(b"&comp=");
+            writer.put_slice(comp.to_string().as_bytes());
         }
         if let Some(enc) = &self.enc {
-            parts.push(format!(
-                "enc={}",
-                crate::protocol::messages::encode_value(enc)
-            ));
+            writer.put_slice(b"&enc=");
+            writer.put_slice(crate::protocol::messages::encode_value(enc).as_bytes());
         }
         if let Some(mtu) = self.mtu {
-            parts.push(format!("mtu={mtu}"));
+            writer.put_slice(b"&mtu=");
+            writer.put_slice(mtu.to_string().as_bytes());
         }
         if let Some(imgserver) = self.imgserver {
-            parts.push(format!("imgserver={imgserver}"));
+            writer.put_slice(b"&imgserver=");
+            writer.put_slice(imgserver.to_string().as_bytes());
         }
 
-        // Join with spaces between command and first param, then & between params
-        if parts.len() <= 1 {
-            Ok(parts.join(""))
-        } else {
-            Ok(format!("{} {}", parts[0], parts[1..].join("&")))
-        }
+        Ok(writer.into_string())
     }
 
     fn requires_auth(&self) -> bool {
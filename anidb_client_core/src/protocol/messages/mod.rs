@@ -25,8 +25,10 @@ pub use mylist::{
 pub use response::{Response, ResponseParser};
 
 use crate::protocol::error::{ProtocolError, Result};
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt;
+use std::sync::OnceLock;
 
 /// Parameter separator used in AniDB protocol
 pub const PARAM_SEPARATOR: char = '|';
@@ -99,6 +101,129 @@ pub trait AniDBResponse: fmt::Debug + Send + Sync {
     }
 }
 
+/// Patterns escaped by [`encode_value`], in the order the Aho-Corasick automaton below
+/// indexes them. `"\r\n"` must outrank the overlapping `"\n"` pattern so a Windows-style
+/// line ending collapses to a single `<br />` instead of an empty match plus one.
+const ESCAPE_PATTERNS: &[(&str, &str)] = &[
+    ("&", "&amp;"),
+    ("\r\n", ENCODED_NEWLINE),
+    ("\n", ENCODED_NEWLINE),
+    ("\r", ""),
+];
+
+/// A single state of the `encode_value` escape automaton
+///
+/// `goto` is the fully-closed transition table (trie edges plus failure-link fallbacks,
+/// precomputed so matching never needs to walk fail links at scan time). `output` is the
+/// longest escape pattern ending at this state once failure-link outputs are unioned in,
+/// which is exactly the leftmost-longest match for that end position.
+struct AcState {
+    goto: [usize; 256],
+    output: Option<(usize, &'static str)>,
+    /// Real (non-fail-routed) trie children, used only to decide whether a match found at
+    /// this state could still extend into a longer one (e.g. `"\r"` into `"\r\n"`) before
+    /// it's committed.
+    real_child: [Option<usize>; 256],
+}
+
+/// Precompiled Aho-Corasick automaton over [`ESCAPE_PATTERNS`]
+struct EscapeAutomaton {
+    states: Vec<AcState>,
+}
+
+impl EscapeAutomaton {
+    fn build() -> Self {
+        // Trie construction: state 0 is the root, edges are keyed by byte since every
+        // escape pattern is pure ASCII.
+        let mut children: Vec<[Option<usize>; 256]> = vec![[None; 256]];
+        let mut depth: Vec<usize> = vec![0];
+        let mut direct_output: Vec<Option<(usize, &'static str)>> = vec![None];
+
+        for &(pattern, replacement) in ESCAPE_PATTERNS {
+            let mut state = 0usize;
+            for &byte in pattern.as_bytes() {
+                state = match children[state][byte as usize] {
+                    Some(next) => next,
+                    None => {
+                        children.push([None; 256]);
+                        depth.push(depth[state] + 1);
+                        direct_output.push(None);
+                        let next = children.len() - 1;
+                        children[state][byte as usize] = Some(next);
+                        next
+                    }
+                };
+            }
+            direct_output[state] = Some((pattern.len(), replacement));
+        }
+
+        // Failure links via BFS over the trie: a node's fail link is the longest proper
+        // suffix of its prefix that is also a prefix of some pattern. Processing states in
+        // BFS order means a state's fail link always has a smaller depth and so is already
+        // finalized (goto and output) by the time we reach it.
+        let mut fail = vec![0usize; children.len()];
+        let mut goto: Vec<[usize; 256]> = vec![[0usize; 256]; children.len()];
+        let mut output = direct_output.clone();
+        let mut queue = std::collections::VecDeque::new();
+
+        for byte in 0..256 {
+            match children[0][byte] {
+                Some(next) => {
+                    goto[0][byte] = next;
+                    fail[next] = 0;
+                    queue.push_back(next);
+                }
+                None => goto[0][byte] = 0,
+            }
+        }
+
+        while let Some(state) = queue.pop_front() {
+            output[state] = match (output[state], output[fail[state]]) {
+                (Some((len, rep)), Some((fail_len, fail_rep))) if fail_len > len => {
+                    Some((fail_len, fail_rep))
+                }
+                (Some(out), _) => Some(out),
+                (None, fail_out) => fail_out,
+            };
+
+            for byte in 0..256 {
+                match children[state][byte] {
+                    Some(next) => {
+                        fail[next] = goto[fail[state]][byte];
+                        goto[state][byte] = next;
+                        queue.push_back(next);
+                    }
+                    None => goto[state][byte] = goto[fail[state]][byte],
+                }
+            }
+        }
+
+        let states = goto
+            .into_iter()
+            .zip(output)
+            .zip(children)
+            .map(|((goto, output), real_child)| AcState {
+                goto,
+                output,
+                real_child,
+            })
+            .collect();
+
+        Self { states }
+    }
+
+    fn get() -> &'static Self {
+        static AUTOMATON: OnceLock<EscapeAutomaton> = OnceLock::new();
+        AUTOMATON.get_or_init(Self::build)
+    }
+}
+
+thread_local! {
+    // Reused across calls so encoding a stream of command parameters doesn't allocate a
+    // fresh `String` for every one of them.
+    static ENCODE_BUFFER: RefCell<String> = RefCell::new(String::with_capacity(256));
+}
+
 /// Encode a value for AniDB protocol transmission
 ///
 /// According to AniDB protocol documentation:
@@ -108,24 +233,54 @@ pub trait AniDBResponse: fmt::Debug + Send + Sync {
 ///
 /// Based on working implementations and AniDB's actual behavior:
 /// - Only & needs to be encoded as &amp;
-/// - Newlines are encoded as <br />
+/// - Newlines (including `\r\n`) are encoded as <br />
 /// - Other special characters are sent as-is (UTF-8 encoded at packet level)
+///
+/// Scans the input in a single left-to-right pass via a precompiled Aho-Corasick
+/// automaton instead of matching each escape target separately, since this runs once per
+/// parameter of every outgoing command. Non-ASCII UTF-8 bytes never collide with a
+/// pattern byte, so multi-byte characters pass through untouched and match boundaries
+/// always land on char boundaries.
 pub fn encode_value(value: &str) -> String {
-    let mut result = String::with_capacity(value.len() + 10);
-
-    for ch in value.chars() {
-        match ch {
-            // HTML entity encoding for ampersand (required by AniDB)
-            '&' => result.push_str("&amp;"),
-            // Newline encoding
-            '\n' => result.push_str(ENCODED_NEWLINE),
-            '\r' => continue, // Skip carriage returns
-            // All other characters pass through unchanged
-            _ => result.push(ch),
+    let automaton = EscapeAutomaton::get();
+    let bytes = value.as_bytes();
+
+    ENCODE_BUFFER.with(|buffer| {
+        let mut result = buffer.borrow_mut();
+        result.clear();
+        result.reserve(value.len());
+
+        let mut state = 0usize;
+        let mut literal_start = 0usize;
+        let mut i = 0usize;
+
+        while i < bytes.len() {
+            state = automaton.states[state].goto[bytes[i] as usize];
+
+            if let Some((len, replacement)) = automaton.states[state].output {
+                // A longer pattern may still be reachable from here (e.g. having just
+                // matched "\r", "\r\n" is one byte away), so only commit once the next
+                // byte can't extend this match further; otherwise keep walking from
+                // `state` and let the extended match take priority when it commits.
+                let can_extend = bytes.get(i + 1).is_some_and(|&next| {
+                    automaton.states[state].real_child[next as usize].is_some()
+                });
+
+                if !can_extend {
+                    let match_start = i + 1 - len;
+                    result.push_str(&value[literal_start..match_start]);
+                    result.push_str(replacement);
+                    literal_start = i + 1;
+                    state = 0;
+                }
+            }
+
+            i += 1;
         }
-    }
 
-    result
+        result.push_str(&value[literal_start..]);
+        result.clone()
+    })
 }
 
 /// Decode a value from AniDB protocol format
@@ -8,9 +8,10 @@
 //! - Thread safety guarantees
 
 use anidb_client_core::ffi::{
-    AniDBConfig, AniDBFileResult, AniDBHashAlgorithm, AniDBProcessOptions, AniDBResult,
-    anidb_client_create, anidb_client_create_with_config, anidb_client_destroy,
-    anidb_client_get_last_error, anidb_free_file_result, anidb_init, anidb_process_file,
+    AniDBConfig, AniDBErrorCategory, AniDBErrorInfo, AniDBFileResult, AniDBHashAlgorithm,
+    AniDBProcessOptions, AniDBResult, anidb_client_create, anidb_client_create_with_config,
+    anidb_client_destroy, anidb_client_get_last_error, anidb_free_error_info,
+    anidb_free_file_result, anidb_get_last_error_info, anidb_init, anidb_process_file,
 };
 use std::ffi::{CString, c_char};
 use std::ptr;
@@ -150,6 +151,51 @@ fn test_buffer_overflow_prevention() {
     let _ = anidb_client_destroy(handle);
 }
 
+/// Test that structured error info survives the FFI boundary and is freed correctly
+#[test]
+#[serial_test::serial]
+fn test_structured_error_info_roundtrip() {
+    let _ = anidb_init(1);
+
+    let mut handle: *mut std::ffi::c_void = ptr::null_mut();
+    let _ = anidb_client_create(&mut handle);
+
+    // No error yet: nothing to report.
+    let mut info_ptr: *mut AniDBErrorInfo = ptr::null_mut();
+    let result = anidb_get_last_error_info(handle, &mut info_ptr);
+    assert_eq!(result, AniDBResult::ErrorUnknown);
+    assert!(info_ptr.is_null());
+
+    // Trigger a file-not-found error.
+    let file_path = CString::new("/nonexistent/path/to/file.mkv").unwrap();
+    let algorithms = [AniDBHashAlgorithm::ED2K];
+    let options = AniDBProcessOptions {
+        algorithms: algorithms.as_ptr(),
+        algorithm_count: 1,
+        enable_progress: 0,
+        progress_callback: None,
+        user_data: ptr::null_mut(),
+    };
+    let mut result_ptr: *mut AniDBFileResult = ptr::null_mut();
+    let _ = anidb_process_file(handle, file_path.as_ptr(), &options, &mut result_ptr);
+
+    let result = anidb_get_last_error_info(handle, &mut info_ptr);
+    assert_eq!(result, AniDBResult::Success);
+    assert!(!info_ptr.is_null());
+
+    unsafe {
+        let info = &*info_ptr;
+        assert_eq!(info.error_code, AniDBResult::ErrorFileNotFound);
+        assert_eq!(info.category, AniDBErrorCategory::Io);
+        assert!(!info.retryable);
+        assert!(!info.message.is_null());
+
+        anidb_free_error_info(info_ptr);
+    }
+
+    let _ = anidb_client_destroy(handle);
+}
+
 /// Test that panics don't cross the FFI boundary
 #[test]
 #[serial_test::serial]
@@ -2,7 +2,7 @@
 
 use anidb_client_core::hashing::HashAlgorithm;
 use anidb_client_core::pipeline::{
-    HashingStage, PipelineConfig, ProcessingStage, StreamingPipelineBuilder, ValidationStage,
+    ByteSliceStage, HashingStage, PipelineConfig, StreamingPipelineBuilder, ValidationStage,
 };
 use std::sync::Arc;
 use std::time::Instant;
@@ -44,7 +44,7 @@ impl ChunkCountingStage {
 }
 
 #[async_trait::async_trait]
-impl ProcessingStage for ChunkCountingStage {
+impl ByteSliceStage for ChunkCountingStage {
     async fn process(&mut self, chunk: &[u8]) -> anidb_client_core::Result<()> {
         *self.count.lock().unwrap() += 1;
         self.chunk_sizes.lock().unwrap().push(chunk.len());
@@ -133,7 +133,7 @@ impl SimpleProgressStage {
 }
 
 #[async_trait::async_trait]
-impl ProcessingStage for SimpleProgressStage {
+impl ByteSliceStage for SimpleProgressStage {
     async fn process(&mut self, chunk: &[u8]) -> anidb_client_core::Result<()> {
         self.bytes_processed += chunk.len() as u64;
         let _ = self
@@ -324,7 +324,7 @@ async fn test_pipeline_stage_ordering() {
     }
 
     #[async_trait::async_trait]
-    impl ProcessingStage for OrderTrackingStage {
+    impl ByteSliceStage for OrderTrackingStage {
         async fn process(&mut self, _chunk: &[u8]) -> anidb_client_core::Result<()> {
             self.order.lock().unwrap().push(self.id);
             Ok(())
@@ -8,9 +8,21 @@ use anidb_client_core::{Error, HashAlgorithm};
 use anidb_test_utils::builders::{TestDataBuilder, TestFileBuilder as TestFileGenerator};
 use anidb_test_utils::mocks::MockFileSystem;
 use anidb_test_utils::performance::{CoverageReporter, PerformanceTracker, TestHarness};
+use anidb_test_utils::verification::{ED2K_CHUNK_SIZE, FileVerifier};
+use md4::{Digest, Md4};
 use std::path::PathBuf;
 use tempfile::TempDir;
 
+/// MD4 hash of the deterministic `i % 256` chunk pattern `generate_corrupted_file_with_chunks`
+/// writes for bytes outside the corrupted ranges, used to build `expected_chunk_hashes` for
+/// [`FileVerifier::verify_chunks`] in tests.
+fn uncorrupted_chunk_hash(start: usize, end: usize) -> String {
+    let bytes: Vec<u8> = (start..end).map(|i| (i % 256) as u8).collect();
+    let mut hasher = Md4::new();
+    hasher.update(&bytes);
+    format!("{:x}", hasher.finalize())
+}
+
 /// Test infrastructure module tests
 #[cfg(test)]
 mod test_file_generator_tests {
@@ -105,6 +117,52 @@ mod test_file_generator_tests {
     }
 }
 
+#[cfg(test)]
+mod chunk_verification_tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_chunks_flags_only_corrupted_chunks() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut generator = TestFileGenerator::new(temp_dir.path());
+
+        let size = ED2K_CHUNK_SIZE + 1000;
+        let file = generator
+            .generate_corrupted_file_with_chunks("chunked.mkv", size, &[1])
+            .unwrap();
+
+        let expected_hashes = vec![
+            uncorrupted_chunk_hash(0, ED2K_CHUNK_SIZE),
+            uncorrupted_chunk_hash(ED2K_CHUNK_SIZE, size),
+        ];
+
+        let mismatches = FileVerifier::verify_chunks(&file, &expected_hashes).unwrap();
+
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].index, 1);
+        assert_eq!(mismatches[0].byte_range, ED2K_CHUNK_SIZE as u64..size as u64);
+    }
+
+    #[test]
+    fn test_verify_chunks_reports_no_mismatches_when_uncorrupted() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut generator = TestFileGenerator::new(temp_dir.path());
+
+        let size = ED2K_CHUNK_SIZE + 1000;
+        let file = generator
+            .generate_corrupted_file_with_chunks("clean.mkv", size, &[])
+            .unwrap();
+
+        let expected_hashes = vec![
+            uncorrupted_chunk_hash(0, ED2K_CHUNK_SIZE),
+            uncorrupted_chunk_hash(ED2K_CHUNK_SIZE, size),
+        ];
+
+        let mismatches = FileVerifier::verify_chunks(&file, &expected_hashes).unwrap();
+        assert!(mismatches.is_empty());
+    }
+}
+
 #[cfg(test)]
 mod test_data_builder_tests {
     use super::*;
@@ -341,6 +399,85 @@ mod performance_tracker_tests {
         assert!(metrics.memory_usage.is_some());
         assert!(metrics.peak_memory.is_some());
     }
+
+    #[test]
+    fn test_baseline_has_bootstrap_confidence_interval() {
+        let mut tracker = PerformanceTracker::new();
+
+        for _ in 0..10 {
+            let op_id = tracker.start_tracking("bootstrap_op");
+            std::thread::sleep(Duration::from_millis(2));
+            tracker.finish_tracking(op_id);
+        }
+        tracker.establish_baseline("bootstrap_op");
+
+        let baseline = tracker.get_baseline("bootstrap_op").unwrap();
+        assert_eq!(baseline.raw_samples.len(), 10);
+        assert_eq!(baseline.point_estimate, baseline.average_duration);
+        assert!(baseline.ci_lower <= baseline.point_estimate);
+        assert!(baseline.point_estimate <= baseline.ci_upper);
+    }
+
+    #[test]
+    fn test_regression_flags_statistical_significance() {
+        let mut tracker = PerformanceTracker::new();
+
+        for _ in 0..5 {
+            let op_id = tracker.start_tracking("stable_op");
+            std::thread::sleep(Duration::from_millis(1));
+            tracker.finish_tracking(op_id);
+        }
+        tracker.establish_baseline("stable_op");
+
+        let op_id = tracker.start_tracking("stable_op");
+        std::thread::sleep(Duration::from_millis(100)); // Wildly outside the baseline's CI
+        tracker.finish_tracking(op_id);
+
+        let regression = tracker.check_regression("stable_op", 1.5).unwrap();
+        assert!(regression.statistically_significant);
+    }
+
+    #[test]
+    fn test_throughput_tracking_reports_bytes_per_second() {
+        let mut tracker = PerformanceTracker::new();
+
+        let op_id = tracker.start_tracking_with_throughput("ed2k_hash_throughput", 1024 * 1024);
+        std::thread::sleep(Duration::from_millis(10));
+        tracker.finish_tracking(op_id);
+
+        let metrics = tracker.get_metrics("ed2k_hash_throughput").unwrap();
+        assert!(metrics.bytes_per_second().unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_metric_without_throughput_has_no_bytes_per_second() {
+        let mut tracker = PerformanceTracker::new();
+
+        let op_id = tracker.start_tracking("no_throughput_op");
+        tracker.finish_tracking(op_id);
+
+        let metrics = tracker.get_metrics("no_throughput_op").unwrap();
+        assert!(metrics.bytes_per_second().is_none());
+    }
+
+    #[test]
+    fn test_report_includes_throughput_column_only_for_tracked_bytes() {
+        let mut tracker = PerformanceTracker::new();
+
+        let with_bytes = tracker.start_tracking_with_throughput("with_bytes", 2 * 1024 * 1024);
+        tracker.finish_tracking(with_bytes);
+        let without_bytes = tracker.start_tracking("without_bytes");
+        tracker.finish_tracking(without_bytes);
+
+        let report = tracker.generate_report();
+        assert!(report.contains("with_bytes") && report.contains("MiB/s"));
+        assert!(
+            report
+                .lines()
+                .find(|line| line.starts_with("without_bytes"))
+                .is_some_and(|line| !line.contains("MiB/s"))
+        );
+    }
 }
 
 #[cfg(test)]
@@ -397,11 +534,38 @@ mod coverage_reporter_tests {
         assert!(report.contains("network: 88.5%"));
         assert!(report.contains("Overall:"));
     }
+
+    #[test]
+    fn test_coverage_report_to_json() {
+        let mut reporter = CoverageReporter::new();
+        reporter.add_module_coverage("core", 94.2);
+
+        let json = reporter.to_json();
+
+        assert_eq!(json["modules"]["core"], 94.2);
+        assert_eq!(json["overall"], 94.2);
+    }
+
+    #[test]
+    fn test_coverage_report_to_junit_xml() {
+        let mut reporter = CoverageReporter::new();
+        reporter.add_module_coverage("core", 94.2);
+
+        let xml = reporter.to_junit_xml();
+
+        assert!(xml.starts_with("<?xml"));
+        assert!(xml.contains("<testsuite name=\"coverage\" tests=\"1\">"));
+        assert!(xml.contains("<testcase name=\"core\""));
+        assert!(xml.contains("94.2%"));
+    }
 }
 
 #[cfg(test)]
 mod test_harness_tests {
     use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
 
     #[test]
     fn test_create_test_harness() {
@@ -488,4 +652,227 @@ mod test_harness_tests {
         assert_eq!(benchmark_results.len(), 1);
         assert!(benchmark_results.contains_key("ed2k_1mb"));
     }
+
+    #[test]
+    fn test_run_all_tests_shuffled_runs_every_case_and_records_seed() {
+        let mut harness = TestHarness::new();
+        harness.add_test_case("case_a", Box::new(|| Ok(())));
+        harness.add_test_case("case_b", Box::new(|| Ok(())));
+        harness.add_test_case("case_c", Box::new(|| Ok(())));
+
+        let results = harness.run_all_tests_shuffled(7);
+
+        assert_eq!(results.seed, Some(7));
+        assert_eq!(results.total_tests, 3);
+        assert_eq!(results.passed_tests, 3);
+        let mut names: Vec<&str> = results.cases.iter().map(|c| c.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["case_a", "case_b", "case_c"]);
+    }
+
+    #[test]
+    fn test_run_all_tests_shuffled_is_reproducible_for_a_given_seed() {
+        let mut harness = TestHarness::new();
+        harness.add_test_case("case_a", Box::new(|| Ok(())));
+        harness.add_test_case("case_b", Box::new(|| Ok(())));
+        harness.add_test_case("case_c", Box::new(|| Ok(())));
+
+        let first: Vec<String> = harness
+            .run_all_tests_shuffled(99)
+            .cases
+            .into_iter()
+            .map(|c| c.name)
+            .collect();
+        let second: Vec<String> = harness
+            .run_all_tests_shuffled(99)
+            .cases
+            .into_iter()
+            .map(|c| c.name)
+            .collect();
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_resolve_shuffle_seed_honors_env_var() {
+        // SAFETY: this test doesn't run concurrently with other tests that read/write
+        // ANIDB_TEST_SEED.
+        unsafe {
+            std::env::set_var("ANIDB_TEST_SEED", "424242");
+        }
+        let seed = TestHarness::resolve_shuffle_seed();
+        unsafe {
+            std::env::remove_var("ANIDB_TEST_SEED");
+        }
+
+        assert_eq!(seed, 424242);
+    }
+
+    #[test]
+    fn test_resolve_shuffle_seed_falls_back_when_env_var_unset() {
+        unsafe {
+            std::env::remove_var("ANIDB_TEST_SEED");
+        }
+        // Two calls in quick succession should still produce a value derived from the
+        // clock rather than a hardcoded constant; just assert it doesn't panic and is
+        // nonzero, since asserting on nanosecond values would be flaky.
+        let seed = TestHarness::resolve_shuffle_seed();
+        assert!(seed > 0);
+    }
+
+    #[test]
+    fn test_watch_reruns_affected_cases_and_benchmarks_matching_pattern() {
+        let temp_dir = TempDir::new().unwrap();
+        let watched_path = temp_dir.path().join("dep.rs");
+        std::fs::write(&watched_path, b"v1").unwrap();
+
+        let mut harness = TestHarness::new();
+
+        let case_runs = Arc::new(AtomicUsize::new(0));
+        let case_runs2 = case_runs.clone();
+        harness.add_watched_test_case(
+            "ed2k_case",
+            vec![watched_path.clone()],
+            move || {
+                case_runs2.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            },
+        );
+
+        let matching_bench_runs = Arc::new(AtomicUsize::new(0));
+        let matching_bench_runs2 = matching_bench_runs.clone();
+        harness.add_benchmark(
+            "ed2k_bench",
+            Box::new(move || {
+                matching_bench_runs2.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }),
+        );
+
+        let other_bench_runs = Arc::new(AtomicUsize::new(0));
+        let other_bench_runs2 = other_bench_runs.clone();
+        harness.add_benchmark(
+            "md4_bench",
+            Box::new(move || {
+                other_bench_runs2.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }),
+        );
+
+        let modifier = std::thread::spawn({
+            let watched_path = watched_path.clone();
+            move || {
+                std::thread::sleep(Duration::from_millis(20));
+                std::fs::write(&watched_path, b"v2").unwrap();
+            }
+        });
+
+        let mut polls = 0;
+        harness.watch(
+            &[temp_dir.path().to_path_buf()],
+            Duration::from_millis(5),
+            Duration::from_millis(15),
+            Some("ed2k"),
+            || {
+                polls += 1;
+                polls < 20
+            },
+        );
+        modifier.join().unwrap();
+
+        assert_eq!(case_runs.load(Ordering::SeqCst), 1);
+        assert_eq!(matching_bench_runs.load(Ordering::SeqCst), 1);
+        assert_eq!(other_bench_runs.load(Ordering::SeqCst), 0);
+
+        // Case/benchmark durations from the re-run should have been folded into the
+        // harness's own tracker, establishing a baseline that survives the call.
+        let tracker = harness.performance_tracker().unwrap();
+        assert!(tracker.get_baseline("ed2k_case").is_some());
+        assert!(tracker.get_baseline("ed2k_bench").is_some());
+    }
+
+    #[test]
+    fn test_watch_debounces_a_burst_of_rapid_changes_into_one_rerun() {
+        let temp_dir = TempDir::new().unwrap();
+        let watched_path = temp_dir.path().join("dep.rs");
+        std::fs::write(&watched_path, b"v0").unwrap();
+
+        let mut harness = TestHarness::new();
+        let case_runs = Arc::new(AtomicUsize::new(0));
+        let case_runs2 = case_runs.clone();
+        harness.add_watched_test_case("case_a", vec![watched_path.clone()], move || {
+            case_runs2.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        });
+
+        // Fire off a burst of saves in quick succession, each well inside the debounce
+        // window, so they should collapse into a single re-run.
+        let modifier = std::thread::spawn({
+            let watched_path = watched_path.clone();
+            move || {
+                for i in 1..=5u8 {
+                    std::thread::sleep(Duration::from_millis(2));
+                    std::fs::write(&watched_path, [i]).unwrap();
+                }
+            }
+        });
+
+        let mut polls = 0;
+        harness.watch(
+            &[temp_dir.path().to_path_buf()],
+            Duration::from_millis(5),
+            Duration::from_millis(50),
+            None,
+            || {
+                polls += 1;
+                polls < 30
+            },
+        );
+        modifier.join().unwrap();
+
+        assert_eq!(case_runs.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_results_to_json() {
+        let mut harness = TestHarness::new();
+        harness.add_test_case("passing_test", Box::new(|| Ok(())));
+        harness.add_test_case(
+            "failing_test",
+            Box::new(|| {
+                Err(Error::Validation(ValidationError::invalid_configuration(
+                    "Test failure",
+                )))
+            }),
+        );
+
+        let json = harness.run_all_tests().to_json();
+
+        assert_eq!(json["total_tests"], 2);
+        assert_eq!(json["passed_tests"], 1);
+        assert_eq!(json["failed_tests"], 1);
+        assert_eq!(json["cases"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_results_to_junit_xml() {
+        let mut harness = TestHarness::new();
+        harness.add_test_case("passing_test", Box::new(|| Ok(())));
+        harness.add_test_case(
+            "failing_test",
+            Box::new(|| {
+                Err(Error::Validation(ValidationError::invalid_configuration(
+                    "Test failure",
+                )))
+            }),
+        );
+
+        let xml = harness.run_all_tests().to_junit_xml("my_suite");
+
+        assert!(xml.starts_with("<?xml"));
+        assert!(xml.contains("<testsuite name=\"my_suite\" tests=\"2\" failures=\"1\""));
+        assert!(xml.contains("<testcase name=\"passing_test\""));
+        assert!(xml.contains("<testcase name=\"failing_test\""));
+        assert!(xml.contains("<failure message=\"Invalid configuration: Test failure\"/>"));
+    }
 }